@@ -18,6 +18,9 @@ use quote::ToTokens;
 ///       #[bind(label = "commit.t")]                 // optional, default is "Commit.t"
 ///       #[bind(skip)]                               // optional: do not bind this field
 ///   }
+///
+/// Generic structs get generic impls with the struct's own bounds, so every bound field type
+/// must be `CanonicalEncode` under them (e.g. `struct Commit<G: PrimeGroup> { t: G }`).
 #[proc_macro_derive(FsrBindable, attributes(bind))]
 pub fn derive_fsr_bindable(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -169,6 +172,7 @@ fn do_derive(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 );
 
                 field_label_consts.push(quote! {
+                    #[allow(non_upper_case_globals)]
                     pub const #const_ident: &'static str = #label_lit;
                 });
             }
@@ -182,8 +186,9 @@ fn do_derive(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     }
 
     // Add an inherent impl that exports MSG_LABEL and LABEL_<field> for the struct
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let labels_impl = quote! {
-        impl #type_ident {
+        impl #impl_generics #type_ident #ty_generics #where_clause {
             pub const MSG_LABEL: &'static str = #prefix_lit;
             #( #field_label_consts )*
         }
@@ -199,7 +204,7 @@ fn do_derive(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
 
     // NOTE: we implement the *runtime* trait `fsr_core::Bindable`
     let expanded = quote! {
-        impl #core::Bindable for #type_ident {
+        impl #impl_generics #core::Bindable for #type_ident #ty_generics #where_clause {
             const OBLIG_MASK: u128 = #mask_expr;
 
             fn bind<A: #core::Absorb>(&self, a: &mut A) {
@@ -0,0 +1,102 @@
+//! Per-challenge cost against transcript length.
+//!
+//! Every oracle keeps a running hash state, so absorbing one more message and deriving the next
+//! challenge should take the same time whether 1 KiB or 1 MiB was absorbed before it; likewise,
+//! sealing Fischlin's first messages should cost the same per repetition for any rho.
+//!
+//!   cargo +nightly bench --bench oracle_cost
+//!   cargo +nightly bench --features sha3,blake3,sponge --bench oracle_cost
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use fsr_core::{
+    Absorb, FSOracle, FischlinOracle, FischlinParams, HashOracle, Oracle, RandomOracle, TranscriptRuntime,
+    U64Challenge,
+};
+
+const DST: &[u8] = b"YavOracle/Bench";
+const PRIOR_KIB: [usize; 3] = [1, 64, 1024];
+
+fn with_prior<O>(mut o: O, kib: usize, mut absorb: impl FnMut(&mut O, &[u8])) -> O {
+    let chunk = [0xabu8; 1024];
+    for _ in 0..kib { absorb(&mut o, &chunk); }
+    o
+}
+
+/// One round of a typed transcript: absorb a 32-byte message, then a challenge.
+fn typed_round<O: Absorb + Oracle>(c: &mut Criterion, name: &str, mk: impl Fn() -> O) {
+    let mut g = c.benchmark_group(format!("typed_round/{name}"));
+    for kib in PRIOR_KIB {
+        let mut o = with_prior(mk(), kib, |o, b| o.absorb_bytes("m", b));
+        g.bench_with_input(BenchmarkId::from_parameter(format!("{kib}KiB")), &kib, |b, _| {
+            b.iter(|| {
+                o.absorb_bytes("t", &[7u8; 32]);
+                black_box(o.challenge::<U64Challenge>("e").unwrap())
+            })
+        });
+    }
+    g.finish();
+}
+
+/// One FS round: absorb a 32-byte first message, then a 32-byte challenge.
+fn fs_round<RO: RandomOracle>(c: &mut Criterion, name: &str, mk: impl Fn() -> RO) {
+    let mut g = c.benchmark_group(format!("fs_round/{name}"));
+    for kib in PRIOR_KIB {
+        let mut fs = with_prior(FSOracle::new(mk()), kib, |o, b| o.absorb("m_i", b));
+        g.bench_with_input(BenchmarkId::from_parameter(format!("{kib}KiB")), &kib, |b, _| {
+            b.iter(|| {
+                fs.absorb("m_i", &[7u8; 32]);
+                black_box(fs.derive_challenge("e_i", &[], 32))
+            })
+        });
+    }
+    g.finish();
+}
+
+/// Fischlin prover setup: begin, push rho 1 KiB first messages, seal (per-repetition throughput).
+fn fischlin_seal<RO: RandomOracle>(c: &mut Criterion, name: &str, mk: impl Fn() -> RO) {
+    let mut g = c.benchmark_group(format!("fischlin_seal/{name}"));
+    let m = vec![0x5au8; 1024];
+    for rho in [64u16, 256, 1024] {
+        let params = FischlinParams::new(rho, 8);
+        let mut f = FischlinOracle::new(mk(), params);
+        g.throughput(Throughput::Elements(rho as u64));
+        g.bench_with_input(BenchmarkId::from_parameter(rho), &rho, |b, _| {
+            b.iter(|| {
+                f.begin(b"statement", b"sid");
+                for _ in 0..rho { f.push_first_message(&m).unwrap(); }
+                f.seal_first_messages().unwrap();
+                black_box(f.predicate_prefix(0).unwrap())
+            })
+        });
+    }
+    g.finish();
+}
+
+fn oracle_cost(c: &mut Criterion) {
+    typed_round(c, "HashOracle", || HashOracle::new(DST));
+    fs_round(c, "HashOracle", || HashOracle::new(DST));
+    fischlin_seal(c, "HashOracle", || HashOracle::new(DST));
+
+    #[cfg(feature = "sha3")]
+    {
+        typed_round(c, "SHA3-256", || fsr_core::Sha3Oracle::new(DST));
+        fs_round(c, "SHA3-256", || fsr_core::Sha3Oracle::new(DST));
+        fs_round(c, "SHAKE256", || fsr_core::Shake256Oracle::new(DST));
+        fischlin_seal(c, "SHA3-256", || fsr_core::Sha3Oracle::new(DST));
+    }
+    #[cfg(feature = "blake3")]
+    {
+        typed_round(c, "BLAKE3", || fsr_core::Blake3Oracle::new(DST));
+        fs_round(c, "BLAKE3", || fsr_core::Blake3Oracle::new(DST));
+    }
+    #[cfg(feature = "sponge")]
+    {
+        typed_round(c, "sponge", || fsr_core::SpongeOracle::new(DST));
+        fs_round(c, "sponge", || fsr_core::SpongeOracle::new(DST));
+    }
+}
+
+criterion_group!(benches, oracle_cost);
+criterion_main!(benches);
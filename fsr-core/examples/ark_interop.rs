@@ -0,0 +1,140 @@
+//! arkworks interop (`fsr_core::ark`): `FsrBindable` messages hold `ark_ec` affine points and
+//! `ark_ff` field elements directly, the typed `Transcript` derives `ark_bn254::Fr` challenges, and
+//! decoding runs arkworks' full validation.
+//!
+//! * Schnorr over BN254 G1 with the typed `Transcript`,
+//! * the same generic Schnorr check on a twisted-Edwards curve (Bandersnatch),
+//! * `Ark<T>` for other ark-serialize types (here an Fq2 element),
+//! * strict decoding: off-curve / off-subgroup points and non-canonical scalars are rejected.
+//!
+//!   cargo +nightly run --features ark --example ark_interop
+
+use ark_bn254::{Fq, Fq2, Fr, G1Affine, G1Projective};
+use ark_ed_on_bls12_381_bandersnatch::{EdwardsAffine, EdwardsProjective};
+use ark_ff::{MontFp, PrimeField as _, UniformRand};
+use ark_serialize::{CanonicalSerialize, Compress};
+use fsr_core::challenge::Bn254Order;
+use fsr_core::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+fn enc<T: CanonicalEncode>(v: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    v.encode(&mut out);
+    out
+}
+
+fn dec<T: CanonicalDecode>(mut bytes: &[u8]) -> Option<T> {
+    let v = T::decode(&mut bytes)?;
+    bytes.is_empty().then_some(v)
+}
+
+// ---------------- messages with arkworks fields ----------------
+
+#[derive(Clone, Debug, FsrBindable)]
+#[bind(prefix = "Statement")]
+struct Statement {
+    #[bind(ob = 0)] y: G1Affine,
+    // Context the proof is bound to; any ark-serialize type goes through `Ark`.
+    #[bind(ob = 0)] ctx: Ark<Fq2>,
+}
+impl Message for Statement {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Statement";
+}
+
+#[derive(Clone, Debug, FsrBindable)]
+#[bind(prefix = "Commit")]
+struct Commit { #[bind(ob = 1)] t: G1Affine }
+impl Message for Commit {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Commit";
+}
+
+#[derive(Clone, Debug, FsrBindable)]
+#[bind(prefix = "Response")]
+struct Response { z: Fr }
+impl Message for Response {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Response";
+}
+
+declare_round!(R1 = [Statement, Commit]);
+
+fn challenge(st: &Statement, c: &Commit) -> Result<Fr> {
+    let tr: R1<HashOracle> = Transcript::new(HashOracle::new(b"YavOracle/Ark/BN254"));
+    let tr = tr.absorb::<{ Statement::OBLIG_MASK }, _>(Statement::LABEL, st);
+    let tr = tr.absorb::<{ Commit::OBLIG_MASK }, _>(Commit::LABEL, c);
+    Ok(tr.challenge::<Fr>("e")?.0)
+}
+
+fn prove(st: &Statement, x: Fr, rng: &mut StdRng) -> Result<(Commit, Response)> {
+    let r = Fr::rand(rng);
+    let c = Commit { t: (G1Projective::generator() * r).into() };
+    let e = challenge(st, &c)?;
+    Ok((c, Response { z: r + e * x }))
+}
+
+fn verify(st: &Statement, c: &Commit, resp: &Response) -> Result<bool> {
+    let e = challenge(st, c)?;
+    Ok(G1Projective::generator() * resp.z == c.t + st.y * e)
+}
+
+// ---------------- the same check on any PrimeGroup ----------------
+
+fn schnorr_fs<G: PrimeGroup>(rng: &mut StdRng) -> bool {
+    let x = G::Scalar::random(rng);
+    let y = G::generator() * x;
+    let r = G::Scalar::random(rng);
+    let t = G::generator() * r;
+    let mut fs = FSOracle::new(HashOracle::new(b"YavOracle/Ark/Generic"));
+    fs.absorb("y", &enc(&y));
+    fs.absorb("t", &enc(&t));
+    let e = <G::Scalar as Challenge>::from_oracle_bytes("e", &fs.derive_challenge("e", &[], <G::Scalar as Challenge>::BYTES));
+    let z = r + e * x;
+    G::generator() * z == t + y * e
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(14);
+
+    // 1) Typed transcript over BN254 with ark fields.
+    let x = Fr::rand(&mut rng);
+    let ctx = Ark(Fq2::new(MontFp!("7"), MontFp!("11")));
+    let st = Statement { y: (G1Projective::generator() * x).into(), ctx };
+    let (c, resp) = prove(&st, x, &mut rng)?;
+    let ok = verify(&st, &c, &resp)?;
+    let other_ctx = Statement { ctx: Ark(Fq2::new(MontFp!("7"), MontFp!("12"))), ..st.clone() };
+    let rejects = !verify(&other_ctx, &c, &resp)?;
+    println!("BN254 G1 Schnorr (typed Transcript): verify = {ok}, other context rejected = {rejects}");
+    assert!(ok && rejects);
+
+    // The arkworks field challenge is the wide reduction of the `challenge` module.
+    let bytes: Vec<u8> = (0..<Fr as Challenge>::BYTES as u8).collect();
+    let wide = WideReduction::<Bn254Order>::from_oracle_bytes("e", &bytes).0;
+    assert_eq!(<Fr as Challenge>::BYTES, WideReduction::<Bn254Order>::BYTES);
+    assert_eq!(<Fr as Challenge>::from_oracle_bytes("e", &bytes), Fr::from_le_bytes_mod_order(&wide.to_le_bytes()));
+
+    // 2) Twisted Edwards and short Weierstrass through PrimeGroup.
+    let te_ok = schnorr_fs::<EdwardsProjective>(&mut rng) && schnorr_fs::<G1Projective>(&mut rng);
+    println!("generic Schnorr on Bandersnatch (TE) and BN254 G1 (SW): {te_ok}");
+    assert!(te_ok);
+
+    // 3) Strict decoding.
+    // Compressed points carry x only: an x with x³ + 3 a non-square is not on BN254.
+    let off_curve = (1u64..).map(Fq::from).find(|&x| G1Affine::get_point_from_x_unchecked(x, false).is_none()).unwrap();
+    assert!(dec::<G1Affine>(&enc(&off_curve)).is_none());
+    // Bandersnatch has cofactor 4: points from a random y are almost never in the prime subgroup.
+    let off_subgroup = (1u64..)
+        .filter_map(|i| EdwardsAffine::get_point_from_y_unchecked(i.into(), true))
+        .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    let mut raw = Vec::new();
+    off_subgroup.serialize_with_mode(&mut raw, Compress::Yes).unwrap();
+    assert!(dec::<EdwardsAffine>(&raw).is_none());
+    assert!(dec::<Fr>(&[0xff; 32]).is_none()); // ≥ r
+    assert_eq!(dec::<G1Affine>(&enc(&st.y)), Some(st.y));
+    assert_eq!(dec::<Ark<Fq2>>(&enc(&ctx)), Some(ctx));
+    println!("off-curve / off-subgroup / non-canonical encodings rejected = true");
+
+    Ok(())
+}
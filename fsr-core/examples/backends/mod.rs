@@ -0,0 +1,22 @@
+//! The backend loop of the generic group examples, pulled in with `#[macro_use] mod backends;`
+//! and built with the `backends` feature (every group backend).
+//!
+//! `for_each_backend!(G, name => body)` evaluates `body` once per backend, in a scope where `G`
+//! is the group type and `name` its display name, so `body` can instantiate a generic `run::<G>`,
+//! pick per-backend sizes by `name` and use `?` from `main`.
+
+macro_rules! for_each_backend {
+    ($g:ident, $name:ident => $body:expr) => {
+        for_each_backend!(@one $g, $name, fsr_core::groups::ristretto::RistrettoPoint, "ristretto255", $body);
+        for_each_backend!(@one $g, $name, fsr_core::groups::secp256k1::ProjectivePoint, "secp256k1", $body);
+        for_each_backend!(@one $g, $name, fsr_core::groups::p256::ProjectivePoint, "P-256", $body);
+        for_each_backend!(@one $g, $name, fsr_core::groups::bls12_381::G1Projective, "BLS12-381 G1", $body);
+        for_each_backend!(@one $g, $name, fsr_core::groups::bls12_381::G2Projective, "BLS12-381 G2", $body);
+        for_each_backend!(@one $g, $name, fsr_core::groups::bls12_381::Gt, "BLS12-381 GT", $body);
+    };
+    (@one $g:ident, $name:ident, $ty:ty, $label:literal, $body:expr) => {{
+        type $g = $ty;
+        let $name: &str = $label;
+        $body;
+    }};
+}
@@ -0,0 +1,164 @@
+//! Batch verification with `batch::verify_fs_batch`: many FS proofs from `sigma::prove_fs`
+//! checked with one multi-scalar multiplication on every backend. Checks
+//! * 64 Schnorr proofs (distinct statements and sids) accepted as a batch, and the time against
+//!   64 `sigma::verify_fs` calls,
+//! * bisection reporting exactly the tampered proofs (a shifted response, a proof moved to
+//!   another sid, a truncated commitment),
+//! * DLEQ, `Linear` (a Pedersen opening), `And` / `Or` and 8-bit `Range` proofs, with one bad
+//!   proof each,
+//! * agreement with `verify_fs` on every proof.
+//!
+//!   cargo +nightly run --release --features backends --example batch_verify
+
+use std::time::Instant;
+
+use fsr_core::batch::verify_fs_batch;
+use fsr_core::protocols::and::And;
+use fsr_core::protocols::dleq::{self, Dleq};
+use fsr_core::protocols::linear::{Homomorphism, Linear, Statement};
+use fsr_core::protocols::or::{Either, Or};
+use fsr_core::protocols::range::{self, Opening, Range};
+use fsr_core::protocols::schnorr::Schnorr;
+use fsr_core::sigma::{decode_exact, encode, prove_fs, verify_fs};
+use fsr_core::{FsProof, HashOracle, LinearSigma, PrimeField, PrimeGroup, Result, SigmaProtocol};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+const DST: &[u8] = b"YavOracle/Batch/FS";
+
+/// (statement, sid, proof) triples.
+type Item<P> = (<P as SigmaProtocol>::Statement, Vec<u8>, FsProof);
+
+/// `verify_fs_batch` on `items`, checked against one `verify_fs` per proof.
+fn check<P: LinearSigma>(items: &[Item<P>], rng: &mut StdRng) -> core::result::Result<(), Vec<usize>> {
+    let refs: Vec<_> = items.iter().map(|(x, sid, proof)| (x, sid.as_slice(), proof)).collect();
+    let batch = verify_fs_batch::<P, _, _>(&HashOracle::new(DST), &refs, rng);
+    let single: Vec<usize> = (0..items.len())
+        .filter(|&i| !verify_fs::<P, _>(HashOracle::new(DST), refs[i].0, refs[i].1, refs[i].2))
+        .collect();
+    assert_eq!(batch.clone().err().unwrap_or_default(), single, "batch and single verification disagree");
+    batch
+}
+
+/// `n` proofs of `P` for (statement, witness) pairs from `instance`, each under its own sid.
+fn proofs<P: LinearSigma>(
+    n: usize,
+    rng: &mut StdRng,
+    mut instance: impl FnMut(&mut StdRng) -> (P::Statement, P::Witness),
+) -> Result<Vec<Item<P>>> {
+    (0..n)
+        .map(|i| {
+            let (x, w) = instance(rng);
+            let sid = format!("batch-{i}").into_bytes();
+            let proof = prove_fs::<P, _, _>(HashOracle::new(DST), &x, &w, &sid, rng)?;
+            Ok((x, sid, proof))
+        })
+        .collect()
+}
+
+/// Replace the Schnorr response of `item` by z + 1 (still decodes, no longer verifies).
+fn bump<G: PrimeGroup>(item: &mut (G, Vec<u8>, FsProof)) {
+    let z = decode_exact::<G::Scalar>(&item.2.z[0]).unwrap();
+    item.2.z[0] = encode(&(z + G::Scalar::one()));
+}
+
+fn run<G: PrimeGroup>(name: &str, n: usize, rng: &mut StdRng) -> Result<()> {
+    let h = G::generator() * G::Scalar::random(rng);
+
+    // Schnorr: n valid proofs, then three bad ones.
+    let mut items = proofs::<Schnorr<G>>(n, rng, |rng| {
+        let w = G::Scalar::random(rng);
+        (G::generator() * w, w)
+    })?;
+    let all_ok = check::<Schnorr<G>>(&items, rng).is_ok();
+    let refs: Vec<_> = items.iter().map(|(x, sid, proof)| (x, sid.as_slice(), proof)).collect();
+    let t = Instant::now();
+    let _ = verify_fs_batch::<Schnorr<G>, _, _>(&HashOracle::new(DST), &refs, rng);
+    let t_batch = t.elapsed();
+    let t = Instant::now();
+    let singles_ok = items.iter().all(|(x, sid, proof)| verify_fs::<Schnorr<G>, _>(HashOracle::new(DST), x, sid, proof));
+    let t_single = t.elapsed();
+
+    bump(&mut items[3]);
+    items[n / 2].1 = b"another sid".to_vec();
+    items[n - 1].2.m[0].pop();
+    let bisected = check::<Schnorr<G>>(&items, rng);
+    let bisect_ok = bisected == Err(vec![3, n / 2, n - 1]);
+
+    // DLEQ under one key.
+    let key = G::Scalar::random(rng);
+    let mut items = proofs::<Dleq<G>>(8, rng, |rng| {
+        let h_i = G::generator() * G::Scalar::random(rng);
+        (dleq::Statement { g: G::generator(), h: h_i, a: G::generator() * key, b: h_i * key }, key)
+    })?;
+    let dleq_ok = check::<Dleq<G>>(&items, rng).is_ok();
+    items[5].0.b = items[5].0.b + G::generator();
+    let dleq_bad = check::<Dleq<G>>(&items, rng);
+
+    // Pedersen openings as `Linear`.
+    let phi = Homomorphism::new(vec![vec![G::generator(), h]]);
+    let mut items = proofs::<Linear<G>>(8, rng, |rng| {
+        let w = vec![G::Scalar::random(rng), G::Scalar::random(rng)];
+        (Statement::image(phi.clone(), &w), w)
+    })?;
+    let linear_ok = check::<Linear<G>>(&items, rng).is_ok();
+    items[0].0.x[0] = items[0].0.x[0] + h;
+    let linear_bad = check::<Linear<G>>(&items, rng);
+
+    // (y1 AND y2) and (y1 OR y2) with only w1 known for the OR.
+    let mut and_items = proofs::<And<Schnorr<G>, Schnorr<G>>>(4, rng, |rng| {
+        let (w1, w2) = (G::Scalar::random(rng), G::Scalar::random(rng));
+        ((G::generator() * w1, G::generator() * w2), (w1, w2))
+    })?;
+    let mut or_items = proofs::<Or<Schnorr<G>, Schnorr<G>>>(4, rng, |rng| {
+        let w1 = G::Scalar::random(rng);
+        ((G::generator() * w1, G::generator() * G::Scalar::random(rng)), Either::Left(w1))
+    })?;
+    let and_ok = check::<And<Schnorr<G>, Schnorr<G>>>(&and_items, rng).is_ok();
+    let or_ok = check::<Or<Schnorr<G>, Schnorr<G>>>(&or_items, rng).is_ok();
+    and_items[2].0 .1 = and_items[2].0 .1 + h;
+    or_items[1].0 .0 = or_items[1].0 .0 + h;
+    let and_bad = check::<And<Schnorr<G>, Schnorr<G>>>(&and_items, rng);
+    let or_bad = check::<Or<Schnorr<G>, Schnorr<G>>>(&or_items, rng);
+
+    // 8-bit range proofs.
+    let mut items = proofs::<Range<G>>(4, rng, |rng| {
+        let w = Opening { v: rng.next_u64() & 0xff, s: G::Scalar::random(rng) };
+        (range::Statement { h, n: 8, c: range::pedersen(h, w.v, w.s) }, w)
+    })?;
+    let range_ok = check::<Range<G>>(&items, rng).is_ok();
+    items[3].0.c = items[3].0.c + G::generator();
+    let range_bad = check::<Range<G>>(&items, rng);
+
+    println!(
+        "{name:<14} {n} Schnorr: batch = {all_ok} in {t_batch:?} (single: {t_single:?}), bisect = {bisect_ok}; \
+         DLEQ = {dleq_ok}, Linear = {linear_ok}, And / Or = {and_ok} / {or_ok}, Range = {range_ok}"
+    );
+    assert!(all_ok, "{name}: Schnorr batch rejected");
+    assert!(singles_ok, "{name}: a Schnorr proof rejected on its own");
+    assert_eq!(bisected, Err(vec![3, n / 2, n - 1]), "{name}: Schnorr bisection");
+    assert!(dleq_ok, "{name}: DLEQ batch rejected");
+    assert_eq!(dleq_bad, Err(vec![5]), "{name}: DLEQ bisection");
+    assert!(linear_ok, "{name}: Linear batch rejected");
+    assert_eq!(linear_bad, Err(vec![0]), "{name}: Linear bisection");
+    assert!(and_ok, "{name}: And batch rejected");
+    assert_eq!(and_bad, Err(vec![2]), "{name}: And bisection");
+    assert!(or_ok, "{name}: Or batch rejected");
+    assert_eq!(or_bad, Err(vec![1]), "{name}: Or bisection");
+    assert!(range_ok, "{name}: Range batch rejected");
+    assert_eq!(range_bad, Err(vec![3]), "{name}: Range bisection");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(22);
+
+    for_each_backend!(G, name => {
+        let n = if matches!(name, "BLS12-381 G2" | "BLS12-381 GT") { 16 } else { 64 };
+        run::<G>(name, n, &mut rng)?
+    });
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! Schnorr in the BLS12-381 target group (`fsr_core::groups::bls12_381`).
+//!
+//! Statement: a BLS public key pk = x·G2 and a G1 point h. The prover shows knowledge of x with
+//! Y = x·e(h, G2) in GT; the verifier never receives Y or the base, it computes both with the
+//! pairing, e(h, G2) and Y = e(h, pk), so the proof is bound to pk itself. Decoding is strict:
+//! points outside the prime-order subgroups and GT elements of the wrong order are rejected.
+//!
+//!   cargo +nightly run --features bls12_381 --example bls12_381_gt_schnorr
+
+use ark_ec::pairing::PairingOutput;
+use ark_ff::{Field, One, PrimeField as _, UniformRand};
+use ark_serialize::{CanonicalSerialize, Compress};
+use fsr_core::groups::bls12_381::{pairing, Bls12_381, G1Affine, G1Projective, G2Affine, G2Projective, Group, Gt, Scalar, G1_BYTES};
+use fsr_core::challenge::Bls12381Order;
+use fsr_core::{Absorb, CanonicalDecode, CanonicalEncode, Challenge, HashOracle, Oracle, RecordingHashOracle, Result, WideReduction};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn enc<T: CanonicalEncode>(v: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    v.encode(&mut out);
+    out
+}
+
+fn dec<T: CanonicalDecode>(mut bytes: &[u8]) -> Option<T> {
+    let v = T::decode(&mut bytes)?;
+    bytes.is_empty().then_some(v)
+}
+
+#[derive(Clone, Copy, Debug)]
+struct GtPublic { h: G1Affine, pk: G2Affine }
+
+fsr_proof_dsl::proof! {
+    name: GtSchnorr;
+    domain: "YavOracle/BLS12-381/GtSchnorr";
+    public: GtPublic;
+    fields {
+        t: Gt     <= absorb    "commit.t";
+        e: Scalar <= challenge "e";
+        z: Scalar <= absorb    "resp.z";
+    }
+    replay {
+        bind "stmt.h" <- pub_in.h;
+        bind "stmt.pk" <- pub_in.pk;
+        bind "commit.t" <- t;
+    }
+    check {{
+        let base = pairing(pub_in.h, G2Projective::generator());
+        let y = pairing(pub_in.h, pub_in.pk);
+        base * z == t + y * e
+    }}
+}
+
+fn prove(pubc: &GtPublic, x: Scalar, rng: &mut StdRng) -> Result<GtSchnorrProof> {
+    let mut ro = RecordingHashOracle::new(HashOracle::new(b"YavOracle/BLS12-381/GtSchnorr"));
+    ro.absorb_bytes("stmt.h", &enc(&pubc.h));
+    ro.absorb_bytes("stmt.pk", &enc(&pubc.pk));
+    let r = Scalar::rand(rng);
+    ro.absorb_bytes("commit.t", &enc(&(pairing(pubc.h, G2Projective::generator()) * r)));
+    let e: Scalar = ro.challenge("e")?;
+    ro.absorb_bytes("resp.z", &enc(&(r + e * x)));
+    Ok(gt_schnorr_prove(&ro.into_parts().1).expect("events decode"))
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(12381);
+    let x = Scalar::rand(&mut rng);
+    let pk: G2Affine = (G2Projective::generator() * x).into();
+    let h: G1Affine = (G1Projective::generator() * Scalar::rand(&mut rng)).into();
+    let pubc = GtPublic { h, pk };
+
+    // The GT statement is the pairing of the BLS signature on h with the G2 generator.
+    let sig = h * x;
+    assert_eq!(pairing(sig, G2Projective::generator()), pairing(h, pk));
+
+    let proof = prove(&pubc, x, &mut rng)?;
+    let bytes = proof.encode();
+    let ok = gt_schnorr_verify(&pubc, &proof) && gt_schnorr_verify_bytes(&pubc, &bytes);
+    let other_pk = GtPublic { pk: (G2Projective::generator() * (x + Scalar::ONE)).into(), ..pubc };
+    let rejects = !gt_schnorr_verify(&other_pk, &proof);
+    println!("GT Schnorr (proof!): {} bytes, verify = {ok}, other pk rejected = {rejects}", bytes.len());
+    assert!(ok && rejects);
+
+    // Strict decoding. A point on E(Fq) outside the order-r subgroup: almost every x works.
+    let off_subgroup = (1u64..)
+        .filter_map(|i| G1Affine::get_point_from_x_unchecked(i.into(), true))
+        .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    let mut raw = Vec::new();
+    off_subgroup.serialize_with_mode(&mut raw, Compress::Yes).unwrap();
+    assert_eq!(raw.len(), G1_BYTES);
+    assert!(dec::<G1Affine>(&raw).is_none());
+    // A random Fq12 element is not in the order-r subgroup of GT.
+    let wrong_order = ark_bls12_381::Fq12::rand(&mut rng);
+    assert!(!wrong_order.pow(Scalar::characteristic()).is_one());
+    assert!(dec::<Gt>(&enc(&PairingOutput::<Bls12_381>(wrong_order))).is_none());
+    assert!(dec::<Scalar>(&[0xff; 32]).is_none()); // ≥ r
+    assert_eq!(dec::<G1Affine>(&enc(&h)), Some(h));
+    assert_eq!(dec::<G2Affine>(&enc(&pk)), Some(pk));
+    assert_eq!(dec::<Gt>(&enc(&pairing(h, pk))), Some(pairing(h, pk)));
+    println!("off-subgroup / wrong-order encodings rejected = true");
+
+    // The generic `crate::ark` impls keep this backend's wire format: scalars as 32 little-endian
+    // bytes, challenges the `WideReduction<Bls12381Order>` of the oracle bytes.
+    let s = Scalar::from(0x0102_0304u64);
+    assert_eq!(enc(&s), [[4u8, 3, 2, 1].as_slice(), &[0; 28]].concat());
+    let bytes: Vec<u8> = (0..<Scalar as Challenge>::BYTES as u8).collect();
+    let wide = WideReduction::<Bls12381Order>::from_oracle_bytes("e", &bytes).0;
+    assert_eq!(<Scalar as Challenge>::BYTES, WideReduction::<Bls12381Order>::BYTES);
+    assert_eq!(<Scalar as Challenge>::from_oracle_bytes("e", &bytes), Scalar::from_le_bytes_mod_order(&wide.to_le_bytes()));
+    println!("scalar encoding / challenge reduction unchanged = true");
+
+    Ok(())
+}
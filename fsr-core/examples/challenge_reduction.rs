@@ -0,0 +1,83 @@
+//! Biased vs unbiased challenge reductions.
+//!
+//! With q = 3·2^62, reducing 8 uniform bytes mod q hits [0, q/3) with probability 1/2 instead
+//! of 1/3. `WideReduction` and `RejectionSampled` stay within sampling noise of 1/3, and the
+//! oracle hands every `Challenge` exactly `C::BYTES` bytes.
+//!
+//!   cargo +nightly run --example challenge_reduction
+
+use fsr_core::challenge::Mod64;
+use fsr_core::{
+    Absorb, CanonicalEncode, Challenge, HashOracle, Oracle, RejectionSampled, Result, TruncatedBits,
+    WideReduction,
+};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+const Q: u64 = 3 << 62;
+type M = Mod64<Q>;
+
+const SAMPLES: usize = 200_000;
+
+/// Fraction of `SAMPLES` values below `bound`, each sampled from `bytes` uniform bytes.
+fn below(bound: u64, bytes: usize, mut sample: impl FnMut(&[u8]) -> u64) -> f64 {
+    let mut rng = StdRng::seed_from_u64(2024);
+    let mut buf = vec![0u8; bytes];
+    let hits = (0..SAMPLES)
+        .filter(|_| {
+            rng.fill_bytes(&mut buf);
+            sample(&buf) < bound
+        })
+        .count();
+    hits as f64 / SAMPLES as f64
+}
+
+/// Records how many bytes the oracle supplied.
+struct Probe(usize);
+impl Challenge for Probe {
+    const BYTES: usize = 40;
+    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self { Probe(input.len()) }
+}
+impl CanonicalEncode for Probe {
+    fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&(self.0 as u64).to_le_bytes()); }
+}
+
+fn main() -> Result<()> {
+    let naive = below(Q / 3, 8, |b| u64::from_le_bytes(b[..8].try_into().unwrap()) % Q);
+    let wide = below(Q / 3, WideReduction::<M>::BYTES, |b| {
+        WideReduction::<M>::from_oracle_bytes("e", b).0.as_u64()
+    });
+    let mut attempts = [0usize; 4];
+    let rejection = below(Q / 3, RejectionSampled::<M>::BYTES, |b| {
+        let (v, attempt) = RejectionSampled::<M>::sample(b);
+        attempts[attempt.map_or(3, |a| a.min(2))] += 1;
+        v.as_u64()
+    });
+    let coin = below(1, TruncatedBits::<1>::BYTES, |b| TruncatedBits::<1>::from_oracle_bytes("e", b).0);
+
+    println!("P[e < q/3] (ideal 1/3 = 0.3333):");
+    println!("  naive 8 bytes mod q   = {naive:.4}");
+    println!("  WideReduction         = {wide:.4}  ({} bytes)", WideReduction::<M>::BYTES);
+    println!("  RejectionSampled      = {rejection:.4}  ({} bytes)", RejectionSampled::<M>::BYTES);
+    println!(
+        "    accepted at attempt 0 / 1 / >=2 / fallback: {} / {} / {} / {}",
+        attempts[0], attempts[1], attempts[2], attempts[3]
+    );
+    println!("  TruncatedBits<1>: P[e = 0] = {coin:.4} (ideal 0.5)");
+
+    let close = |p: f64, ideal: f64| (p - ideal).abs() < 0.01;
+    assert!(!close(naive, 1.0 / 3.0) && close(naive, 0.5));
+    assert!(close(wide, 1.0 / 3.0) && close(rejection, 1.0 / 3.0) && close(coin, 0.5));
+    // Acceptance probability is q / 2^64 = 3/4.
+    assert!(close(attempts[0] as f64 / SAMPLES as f64, 0.75));
+
+    // The oracle's output length follows `Challenge::BYTES`.
+    let mut h = HashOracle::new(b"YavOracle/ChallengeReduction");
+    h.absorb_bytes("m", b"hello");
+    let probe: Probe = h.challenge("e")?;
+    println!("HashOracle supplied {} bytes for a {}-byte challenge", probe.0, Probe::BYTES);
+    assert_eq!(probe.0, Probe::BYTES);
+    let e: WideReduction<M> = h.challenge("e")?;
+    assert!(e.0.as_u64() < Q);
+
+    Ok(())
+}
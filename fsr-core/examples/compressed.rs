@@ -0,0 +1,75 @@
+//! Compressed Σ-protocols with `protocols::compressed`: a Pedersen vector commitment
+//! P = Σ g_i·v_i + h·γ with a linear form L(v) = y, proven in ⌈log2 n⌉ folding rounds on the
+//! typed `Transcript` on every backend. Checks
+//! * n = 1, 5, 64 and 300 (padding for non-powers of two), with proof sizes against a `linear`
+//!   (Maurer) proof of the same opening,
+//! * rejection of a wrong y, another commitment, a tampered fold and a proof with a round cut,
+//! * canonical decoding of the encoded proof.
+//!
+//!   cargo +nightly run --release --features backends --example compressed
+
+use fsr_core::protocols::compressed::{self, Proof, Round, Statement};
+use fsr_core::protocols::linear::{self, Homomorphism};
+use fsr_core::sigma::{decode_exact, encode};
+use fsr_core::{HashOracle, PrimeField, PrimeGroup, Result, Transcript};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+const DST: &[u8] = b"YavOracle/Compressed/FS";
+
+fn round() -> Round<HashOracle> { Transcript::new(HashOracle::new(DST)) }
+
+fn run<G: PrimeGroup>(name: &str, ns: &[usize], rng: &mut StdRng) -> Result<()> {
+    let mut sizes = Vec::new();
+    for &n in ns {
+        // g_1 … g_n, h and k; L weighs v_i by i and ignores γ.
+        let g: Vec<G> = (0..=n).map(|_| G::generator() * G::Scalar::random(rng)).collect();
+        let k = G::generator() * G::Scalar::random(rng);
+        let mut l: Vec<G::Scalar> = (1..=n as u64).map(G::Scalar::from_u64).collect();
+        l.push(G::Scalar::zero());
+        let x: Vec<G::Scalar> = (0..=n).map(|_| G::Scalar::random(rng)).collect();
+        let st = Statement::image(g.clone(), k, l, &x);
+
+        let proof = compressed::prove(round(), &st, &x, rng)?;
+        let bytes = encode(&proof);
+        let decoded = decode_exact::<Proof<G>>(&bytes);
+        // n + 1 ≥ 2 entries, so there is at least one round.
+        let mut tampered = proof.clone();
+        tampered.folds[0].b = tampered.folds[0].b + G::generator();
+        let mut cut = proof.clone();
+        cut.folds.pop();
+        assert!(compressed::verify(round(), &st, &proof), "{name}: n = {n} proof rejected");
+        assert_eq!(decoded.as_ref(), Some(&proof), "{name}: n = {n} proof does not round-trip");
+        assert!(decode_exact::<Proof<G>>(&bytes[..bytes.len() - 1]).is_none(), "{name}: n = {n} truncated proof decodes");
+        assert!(
+            !compressed::verify(round(), &Statement { y: st.y + G::Scalar::one(), ..st.clone() }, &proof),
+            "{name}: n = {n} proof accepted for another y"
+        );
+        assert!(!compressed::verify(round(), &Statement { p: st.p + k, ..st.clone() }, &proof), "{name}: n = {n} proof accepted for another P");
+        assert!(!compressed::verify(round(), &st, &tampered), "{name}: n = {n} proof accepted with a tampered fold");
+        assert!(!compressed::verify(round(), &st, &cut), "{name}: n = {n} proof accepted with a fold dropped");
+
+        let maurer = linear::prove(Transcript::new(HashOracle::new(DST)), &linear::Statement::image(Homomorphism::new(vec![g]), &x), &x, rng)?;
+        sizes.push((n, bytes.len(), maurer.encode().len()));
+    }
+
+    println!("{name:<14} FS prove / verify / reject ok, (n, compressed, Maurer) bytes = {sizes:?}");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(23);
+
+    for_each_backend!(G, name => {
+        let ns: &[usize] = match name {
+            "BLS12-381 G1" => &[1, 5, 64],
+            "BLS12-381 G2" | "BLS12-381 GT" => &[1, 5],
+            _ => &[1, 5, 64, 300],
+        };
+        run::<G>(name, ns, &mut rng)?
+    });
+
+    Ok(())
+}
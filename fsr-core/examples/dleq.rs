@@ -0,0 +1,107 @@
+//! Chaum–Pedersen DLEQ with `protocols::dleq`: log_g(a) = log_h(b) on the typed `Transcript`
+//! (FS) and through the sigma drivers (Fischlin), plus the batched form for many (h_i, b_i) pairs
+//! under one key, on every backend. Checks
+//! * single and batched proofs in both formats,
+//! * rejection of a wrong b, of a proof moved to another statement, and of a batch with one bad
+//!   pair (b_i = x·h_i + g),
+//! * `simulate`, and `extract` recovering x from two transcripts.
+//!
+//!   cargo +nightly run --features backends --example dleq
+
+use fsr_core::protocols::dleq::{self, Batch, BatchRound, Dleq, Round, Statement};
+use fsr_core::{FischlinParams, HashOracle, PrimeField, PrimeGroup, Result, SigmaProtocol, Transcript};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+const FS_DST: &[u8] = b"YavOracle/DLEQ/FS";
+const FISCHLIN_DST: &[u8] = b"YavOracle/DLEQ/Fischlin";
+
+fn round() -> Round<HashOracle> { Transcript::new(HashOracle::new(FS_DST)) }
+fn batch_round() -> BatchRound<HashOracle> { Transcript::new(HashOracle::new(FS_DST)) }
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) -> Result<()> {
+    let sid = b"dleq";
+    let params = FischlinParams::new(16, 8);
+    let x = G::Scalar::random(rng);
+    let g = G::generator();
+    let h = g * G::Scalar::random(rng);
+    let st = Statement { g, h, a: g * x, b: h * x };
+    let wrong_b = Statement { b: st.b + g, ..st };
+
+    // One pair, both formats.
+    let fs = dleq::prove(round(), &st, &x, rng)?;
+    let fischlin = dleq::prove_fischlin(HashOracle::new(FISCHLIN_DST), params, &st, &x, sid, rng)?;
+    let other = Statement { h: h + g, b: (h + g) * x, ..st };
+    let fs_ok = dleq::verify(round(), &st, &fs);
+    let fischlin_ok = dleq::verify_fischlin(HashOracle::new(FISCHLIN_DST), params, &st, sid, &fischlin);
+    let fs_wrong_b = dleq::verify(round(), &wrong_b, &fs);
+    let fs_other = dleq::verify(round(), &other, &fs);
+    let fischlin_wrong_b = dleq::verify_fischlin(HashOracle::new(FISCHLIN_DST), params, &wrong_b, sid, &fischlin);
+
+    // Eight pairs under the same key, one proof each format.
+    let pairs: Vec<(G, G)> = (0..8)
+        .map(|_| {
+            let h_i = g * G::Scalar::random(rng);
+            (h_i, h_i * x)
+        })
+        .collect();
+    let batch = Batch { g, a: st.a, pairs };
+    let batch_fs = dleq::prove_batch(batch_round(), &batch, &x, rng)?;
+    let batch_fischlin =
+        dleq::prove_batch_fischlin(batch_round(), HashOracle::new(FISCHLIN_DST), params, &batch, &x, sid, rng)?;
+    let mut bad = batch.clone();
+    bad.pairs[5].1 = bad.pairs[5].1 + g;
+    let mut fewer = batch.clone();
+    fewer.pairs.pop();
+    let batch_fs_ok = dleq::verify_batch(batch_round(), &batch, &batch_fs);
+    let batch_fischlin_ok =
+        dleq::verify_batch_fischlin(batch_round(), HashOracle::new(FISCHLIN_DST), params, &batch, sid, &batch_fischlin);
+    let batch_fs_bad = dleq::verify_batch(batch_round(), &bad, &batch_fs);
+    let batch_fs_fewer = dleq::verify_batch(batch_round(), &fewer, &batch_fs);
+    let batch_fischlin_bad =
+        dleq::verify_batch_fischlin(batch_round(), HashOracle::new(FISCHLIN_DST), params, &bad, sid, &batch_fischlin);
+    // A prover without a consistent witness cannot make the combined statement hold either.
+    let forged = dleq::prove_batch(batch_round(), &bad, &x, rng)?;
+    let forged_rejected = !dleq::verify_batch(batch_round(), &bad, &forged);
+
+    // simulate / extract.
+    let e = G::Scalar::random(rng);
+    let (c_sim, z_sim) = Dleq::<G>::simulate(&st, &e, rng);
+    let sim_ok = Dleq::<G>::verify(&st, &c_sim, &e, &z_sim);
+    let (c, r) = Dleq::<G>::commit(&st, &x, rng);
+    let e2 = e + G::Scalar::one();
+    let transcripts = [(e, Dleq::<G>::respond(&st, &x, &r, &e)), (e2, Dleq::<G>::respond(&st, &x, &r, &e2))];
+    let extract_ok = Dleq::<G>::extract(&st, &c, &transcripts) == Some(x);
+    let extract_repeated = Dleq::<G>::extract(&st, &c, &[transcripts[0], transcripts[0]]);
+
+    println!(
+        "{name:<14} FS / Fischlin = {fs_ok} / {fischlin_ok}, batch of 8 = {batch_fs_ok} / {batch_fischlin_ok}, \
+         bad pair rejected = {forged_rejected}, simulate = {sim_ok}, extract = {extract_ok}, FS proof = {} bytes",
+        fs.encode().len()
+    );
+    assert!(fs_ok, "{name}: FS proof rejected");
+    assert!(fischlin_ok, "{name}: Fischlin proof rejected");
+    assert!(!fs_wrong_b, "{name}: FS proof accepted with a wrong b");
+    assert!(!fs_other, "{name}: FS proof accepted for another h");
+    assert!(!fischlin_wrong_b, "{name}: Fischlin proof accepted with a wrong b");
+    assert!(batch_fs_ok, "{name}: batched FS proof rejected");
+    assert!(batch_fischlin_ok, "{name}: batched Fischlin proof rejected");
+    assert!(!batch_fs_bad, "{name}: batched FS proof accepted with a bad pair");
+    assert!(!batch_fs_fewer, "{name}: batched FS proof accepted with a pair dropped");
+    assert!(!batch_fischlin_bad, "{name}: batched Fischlin proof accepted with a bad pair");
+    assert!(forged_rejected, "{name}: batch proof over a bad pair accepted");
+    assert!(sim_ok, "{name}: simulated transcript rejected");
+    assert!(extract_ok, "{name}: extract did not recover the witness");
+    assert!(extract_repeated.is_none(), "{name}: extract accepted a repeated challenge");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(19);
+
+    for_each_backend!(G, name => run::<G>(name, &mut rng)?);
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! Special soundness with `testing::extract::check`: every protocol's prover is rewound at its
+//! challenge, `extract` runs on the accepting transcripts, and the witness is checked against
+//! the relation, on every backend. Checks
+//! * Schnorr, `And`, `Or`, 4-bit `Range` (closures over `commit` / `respond`), and `dleq::prove`
+//!   / `linear::prove` on a typed `Transcript` over `&mut Rewind`,
+//! * batched DLEQ forked after its weight challenges (`fork` = number of pairs),
+//! * n_special = 3 (one extra accepting run per check),
+//! * failures: a Schnorr variant whose response does not bind w (`WrongWitness`), a prover with
+//!   fresh coins per run (`Diverged`), a prover that never asks for the fork (`NoFork`), and
+//!   six transcripts of Schnorr over `testing::toy::Toy<5>` (`ChallengeSpaceTooSmall`).
+//!
+//!   cargo +nightly run --features backends,testing --example extractor
+
+use core::marker::PhantomData;
+
+use fsr_core::protocols::and::And;
+use fsr_core::protocols::dleq::{self, Batch, Dleq};
+use fsr_core::protocols::linear::{self, Homomorphism, Linear};
+use fsr_core::protocols::or::{Either, Or};
+use fsr_core::protocols::range::{self, Opening, Range};
+use fsr_core::protocols::schnorr::Schnorr;
+use fsr_core::sigma::{decode_exact, encode};
+use fsr_core::testing::extract::{check, Failure, Rewind};
+use fsr_core::testing::toy::{Toy, Zq};
+use fsr_core::{Absorb, FischlinParams, FsProof, Oracle, PrimeField, PrimeGroup, Result, SigmaProtocol, Transcript};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+/// `P` run by hand on the oracle: commit on a tape seeded with `seed`, absorb a, draw e, respond.
+fn prover<'a, P: SigmaProtocol>(x: &'a P::Statement, w: &'a P::Witness, seed: u64) -> impl FnMut(&mut Rewind) -> Result<(P::Commitment, P::Response)> + 'a {
+    move |oracle| {
+        let (a, state) = P::commit(x, w, &mut StdRng::seed_from_u64(seed));
+        oracle.absorb_bytes("m", &encode(&a));
+        let e = oracle.challenge::<P::Challenge>("e")?;
+        Ok((a.clone(), P::respond(x, w, &state, &e)))
+    }
+}
+
+/// (a, z) from a one-round `FsProof`.
+fn parts<P: SigmaProtocol>(proof: &FsProof) -> (P::Commitment, P::Response) {
+    (decode_exact(&proof.m[0]).expect("commitment"), decode_exact(&proof.z[0]).expect("response"))
+}
+
+/// Schnorr with z = r + e: accepted by `verify` (which checks z·G = a + e·G), but z says nothing
+/// about w, so the extracted "witness" is always 1.
+struct Unbound<G>(PhantomData<G>);
+
+impl<G: PrimeGroup> SigmaProtocol for Unbound<G> {
+    type Statement = G;
+    type Witness = G::Scalar;
+    type Commitment = G;
+    type Challenge = G::Scalar;
+    type Response = G::Scalar;
+    type State = G::Scalar;
+
+    fn commit<R: RngCore + ?Sized>(_y: &G, _w: &G::Scalar, rng: &mut R) -> (G, G::Scalar) {
+        let r = G::Scalar::random(rng);
+        (G::generator() * r, r)
+    }
+    fn respond(_y: &G, _w: &G::Scalar, r: &G::Scalar, e: &G::Scalar) -> G::Scalar { *r + *e }
+    fn verify(_y: &G, a: &G, e: &G::Scalar, z: &G::Scalar) -> bool { G::generator() * *z == *a + G::generator() * *e }
+    fn simulate<R: RngCore + ?Sized>(_y: &G, e: &G::Scalar, rng: &mut R) -> (G, G::Scalar) {
+        let z = G::Scalar::random(rng);
+        (G::generator() * (z - *e), z)
+    }
+    fn extract(_y: &G, _a: &G, transcripts: &[(G::Scalar, G::Scalar)]) -> Option<G::Scalar> {
+        let [(e1, z1), (e2, z2)] = transcripts else { return None };
+        Some((*z1 - *z2) * (*e1 - *e2).invert()?)
+    }
+}
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) -> Result<()> {
+    let params = FischlinParams::new(16, 8);
+    let dlog = |y: &G, w: &G::Scalar| G::generator() * *w == *y;
+    let (w1, w2) = (G::Scalar::random(rng), G::Scalar::random(rng));
+    let (y1, y2) = (G::generator() * w1, G::generator() * w2);
+    let h = G::generator() * G::Scalar::random(rng);
+
+    // Schnorr, at n_special = 2 and 3.
+    let schnorr = check::<Schnorr<G>, _, _>(&params, &y1, dlog, 0, prover::<Schnorr<G>>(&y1, &w1, 1), rng);
+    assert_eq!(schnorr, Ok(w1), "{name}: Schnorr");
+    let schnorr3 = check::<Schnorr<G>, _, _>(&params.with_n_special(3), &y1, dlog, 0, prover::<Schnorr<G>>(&y1, &w1, 2), rng);
+    assert_eq!(schnorr3, Ok(w1), "{name}: Schnorr, n_special = 3");
+
+    // And / Or.
+    let x = (y1, y2);
+    let and = check::<And<Schnorr<G>, Schnorr<G>>, _, _>(&params, &x, |x, w| dlog(&x.0, &w.0) && dlog(&x.1, &w.1), 0, prover::<And<Schnorr<G>, Schnorr<G>>>(&x, &(w1, w2), 3), rng);
+    assert_eq!(and, Ok((w1, w2)), "{name}: And");
+    let w = Either::Right(w2);
+    let either = |x: &(G, G), w: &Either<G::Scalar, G::Scalar>| match w {
+        Either::Left(w) => dlog(&x.0, w),
+        Either::Right(w) => dlog(&x.1, w),
+    };
+    let or = check::<Or<Schnorr<G>, Schnorr<G>>, _, _>(&params, &x, either, 0, prover::<Or<Schnorr<G>, Schnorr<G>>>(&x, &w, 4), rng);
+    assert_eq!(or, Ok(w), "{name}: Or");
+
+    // 4-bit range proof: the extracted opening must open C.
+    let opening = Opening { v: 11, s: G::Scalar::random(rng) };
+    let x = range::Statement { h, n: 4, c: range::pedersen(h, opening.v, opening.s) };
+    let opens = |x: &range::Statement<G>, w: &Opening<G::Scalar>| w.v >> x.n == 0 && range::pedersen(x.h, w.v, w.s) == x.c;
+    let range = check::<Range<G>, _, _>(&params, &x, opens, 0, prover::<Range<G>>(&x, &opening, 5), rng);
+    assert_eq!(range, Ok(opening), "{name}: Range");
+
+    // DLEQ and a Pedersen opening through their typed-transcript provers.
+    let st = dleq::Statement { g: G::generator(), h, a: y1, b: h * w1 };
+    let same_log = |st: &dleq::Statement<G>, x: &G::Scalar| st.g * *x == st.a && st.h * *x == st.b;
+    let dleq = check::<Dleq<G>, _, _>(&params, &st, same_log, 0, |oracle| {
+        let proof = dleq::prove(Transcript::new(oracle), &st, &w1, &mut StdRng::seed_from_u64(6))?;
+        Ok(parts::<Dleq<G>>(&proof))
+    }, rng);
+    assert_eq!(dleq, Ok(w1), "{name}: DLEQ");
+    let phi = Homomorphism::new(vec![vec![G::generator(), h]]);
+    let lw = vec![w1, w2];
+    let lst = linear::Statement::image(phi, &lw);
+    let image = |st: &linear::Statement<G>, w: &Vec<G::Scalar>| st.phi.apply(w) == st.x;
+    let linear = check::<Linear<G>, _, _>(&params, &lst, image, 0, |oracle| {
+        let proof = linear::prove(Transcript::new(oracle), &lst, &lw, &mut StdRng::seed_from_u64(7))?;
+        Ok(parts::<Linear<G>>(&proof))
+    }, rng);
+    assert_eq!(linear, Ok(lw.clone()), "{name}: Linear");
+
+    // Batched DLEQ: one weight challenge per pair comes first, so fork after them. The combined
+    // statement is what the weights give on any oracle that answers them the same way.
+    let pairs: Vec<(G, G)> = (0..3).map(|_| G::generator() * G::Scalar::random(rng)).map(|h_i| (h_i, h_i * w1)).collect();
+    let batch = Batch { g: G::generator(), a: y1, pairs };
+    let (combined, _) = dleq::combine(Transcript::new(Rewind::new(usize::MAX, Vec::new())), &batch)?;
+    let batched = check::<Dleq<G>, _, _>(&params, &combined, same_log, batch.pairs.len(), |oracle| {
+        let proof = dleq::prove_batch(Transcript::new(oracle), &batch, &w1, &mut StdRng::seed_from_u64(8))?;
+        Ok(parts::<Dleq<G>>(&proof))
+    }, rng);
+    assert_eq!(batched, Ok(w1), "{name}: batched DLEQ");
+
+    // What the harness catches.
+    let unbound = check::<Unbound<G>, _, _>(&params, &y1, dlog, 0, prover::<Unbound<G>>(&y1, &w1, 9), rng);
+    let mut coins = StdRng::seed_from_u64(10);
+    let diverged = check::<Schnorr<G>, _, _>(&params, &y1, dlog, 0, |oracle| {
+        let seed = coins.next_u64();
+        prover::<Schnorr<G>>(&y1, &w1, seed)(oracle)
+    }, rng);
+    let no_fork = check::<Schnorr<G>, _, _>(&params, &y1, dlog, 1, prover::<Schnorr<G>>(&y1, &w1, 11), rng);
+    assert_eq!(unbound, Err(Failure::WrongWitness), "{name}: unbound response");
+    assert_eq!(diverged, Err(Failure::Diverged { run: 1 }), "{name}: fresh coins per run");
+    assert_eq!(no_fork, Err(Failure::NoFork { run: 0 }), "{name}: fork never drawn");
+
+    println!("{name:<14} extracted: Schnorr / And / Or / Range / DLEQ / Linear / batch ok, caught unbound / diverged / no fork");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(24);
+
+    // Schnorr over `Toy<5>` has five challenges, too few for six distinct transcripts.
+    let w = Zq::<5>::from_u64(3);
+    let y = Toy::<5>::generator() * w;
+    let dlog = |y: &Toy<5>, w: &Zq<5>| Toy::<5>::generator() * *w == *y;
+    let six = FischlinParams::new(16, 8).with_n_special(6);
+    let small = check::<Schnorr<Toy<5>>, _, _>(&six, &y, dlog, 0, prover::<Schnorr<Toy<5>>>(&y, &w, 12), &mut rng);
+    assert_eq!(small, Err(Failure::ChallengeSpaceTooSmall { run: 5 }), "Toy<5>: six transcripts from five challenges");
+    println!("Toy<5>         caught too small a challenge space");
+
+    for_each_backend!(G, name => run::<G>(name, &mut rng)?);
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! One Schnorr definition, generic over `PrimeGroup`, run on every group backend with
+//! both transforms: FS (`prove!` / `verify!`, challenges of `<G::Scalar as Challenge>::BYTES`)
+//! and Fischlin (`respond_stream`: one scalar addition per predicate try).
+//!
+//!   cargo +nightly run --features backends --example generic_schnorr
+
+use fsr_core::{
+    CanonicalDecode, CanonicalEncode, Challenge, FischlinOracle, FischlinParams, FischlinProof, FsProof,
+    HashOracle, PrimeField, PrimeGroup, Result,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+fn enc<T: CanonicalEncode>(v: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    v.encode(&mut out);
+    out
+}
+
+fn dec<T: CanonicalDecode>(mut bytes: &[u8]) -> Option<T> {
+    let v = T::decode(&mut bytes)?;
+    bytes.is_empty().then_some(v)
+}
+
+/// Fischlin challenges are small integers, little-endian.
+fn small_challenge<F: PrimeField>(e_bytes: &[u8]) -> F {
+    F::from_u64(e_bytes.iter().rev().fold(0, |acc, &b| (acc << 8) | b as u64))
+}
+
+// ---------------- Schnorr over any PrimeGroup ----------------
+
+fn first<G: PrimeGroup>(rng: &mut StdRng) -> (Vec<u8>, G::Scalar) {
+    let r = G::Scalar::random(rng);
+    (enc(&(G::generator() * r)), r)
+}
+
+fn check<G: PrimeGroup>(y: G, m: &[u8], e: G::Scalar, z: &[u8]) -> bool {
+    match (dec::<G>(m), dec::<G::Scalar>(z)) {
+        (Some(t), Some(z)) => G::generator() * z == t + y * e,
+        _ => false,
+    }
+}
+
+fn prove_fs<G: PrimeGroup>(y: G, x: G::Scalar, sid: &[u8], rng: &mut StdRng) -> Result<FsProof> {
+    fsr_proof_dsl::prove! {
+        transform = "fs",
+        oracle = HashOracle::new(b"YavOracle/GenericSchnorr/FS"),
+        rho = 1u16,
+        b = 0u8,
+        statement = enc(&y),
+        sid = sid,
+        challenge_len = <G::Scalar as Challenge>::BYTES,
+        first = |_i| first::<G>(rng),
+        respond = |_i: usize, e: &[u8], r: &G::Scalar| {
+            enc(&(*r + <G::Scalar as Challenge>::from_oracle_bytes("e_i", e) * x))
+        }
+    }
+}
+
+fn verify_fs<G: PrimeGroup>(y: G, sid: &[u8], proof: &FsProof) -> bool {
+    fsr_proof_dsl::verify! {
+        transform = "fs",
+        oracle = HashOracle::new(b"YavOracle/GenericSchnorr/FS"),
+        statement = &enc(&y),
+        sid = sid,
+        proof = proof,
+        challenge_len = <G::Scalar as Challenge>::BYTES,
+        sigma_verify = |_i, m, e, z| check(y, m, <G::Scalar as Challenge>::from_oracle_bytes("e_i", e), z)
+    }
+}
+
+fn prove_fischlin<G: PrimeGroup>(y: G, x: G::Scalar, sid: &[u8], params: FischlinParams, rng: &mut StdRng) -> Result<FischlinProof> {
+    fsr_proof_dsl::prove! {
+        transform = "fischlin",
+        oracle = FischlinOracle::new(HashOracle::new(b"YavOracle/GenericSchnorr/Fischlin"), params),
+        rho = params.rho,
+        b = params.b,
+        statement = enc(&y),
+        sid = sid,
+        first = |_i| first::<G>(rng),
+        respond = |_i: usize, e: &[u8], r: &G::Scalar| enc(&(*r + small_challenge::<G::Scalar>(e) * x)),
+        respond_stream = |_i: usize, r: &G::Scalar| {
+            let mut z = *r;
+            move || {
+                let out = enc(&z);
+                z = z + x;
+                out
+            }
+        }
+    }
+}
+
+fn verify_fischlin<G: PrimeGroup>(y: G, sid: &[u8], proof: &FischlinProof) -> bool {
+    fsr_proof_dsl::verify! {
+        transform = "fischlin",
+        oracle = FischlinOracle::new(HashOracle::new(b"YavOracle/GenericSchnorr/Fischlin"), FischlinParams::new(proof.rho, proof.b)),
+        statement = &enc(&y),
+        sid = sid,
+        proof = proof,
+        sigma_verify = |_i, m, e, z| check(y, m, small_challenge::<G::Scalar>(e), z)
+    }
+}
+
+// ---------------- test matrix ----------------
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) -> Result<()> {
+    let sid = b"generic-schnorr";
+    let x = G::Scalar::random(rng);
+    let y = G::generator() * x;
+    let other = y + G::generator();
+
+    let fs = prove_fs(y, x, sid, rng)?;
+    let fs_ok = verify_fs(y, sid, &fs);
+    let fs_other = verify_fs(other, sid, &fs);
+
+    let params = FischlinParams::new(16, 8);
+    let fischlin = prove_fischlin(y, x, sid, params, rng)?;
+    let fischlin_ok = verify_fischlin(y, sid, &fischlin);
+    let fischlin_other = verify_fischlin(other, sid, &fischlin);
+
+    println!(
+        "{name:<14} FS ({:>4} bytes) = {fs_ok}, Fischlin ({:>5} bytes) = {fischlin_ok}, other statement accepted = {}",
+        fs.encode().len(),
+        fischlin.encode().len(),
+        fs_other || fischlin_other
+    );
+    assert!(fs_ok, "{name}: FS proof rejected");
+    assert!(!fs_other, "{name}: FS proof accepted for another statement");
+    assert!(fischlin_ok, "{name}: Fischlin proof rejected");
+    assert!(!fischlin_other, "{name}: Fischlin proof accepted for another statement");
+
+    // Field/group laws the protocols above rely on.
+    assert_eq!(x * x.invert().unwrap(), G::Scalar::one(), "{name}: x·x⁻¹");
+    assert!(G::Scalar::zero().invert().is_none(), "{name}: 0 has no inverse");
+    assert!((other - y - G::generator()).is_identity(), "{name}: (y + G) − y − G");
+    assert_eq!(y + (-y), G::identity(), "{name}: y + (−y)");
+    assert_eq!(G::generator() * G::Scalar::from_u64(3), G::generator() + G::generator() + G::generator(), "{name}: 3·G");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(13);
+
+    for_each_backend!(G, name => run::<G>(name, &mut rng)?);
+
+    Ok(())
+}
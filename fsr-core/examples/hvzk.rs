@@ -0,0 +1,185 @@
+//! Honest-verifier zero knowledge with `testing::hvzk`: honest and simulated transcripts compared
+//! exactly and by a chi-square test over `testing::toy::Toy<11>` (the order-11 subgroup of
+//! Z_23^*), and checked by the `verify_fs` / `verify_fischlin` round checks on every backend.
+//! Checks
+//! * Schnorr, DLEQ, `Linear` (a Pedersen opening), `And`, `Or` with the witness on either side,
+//!   and a 1-bit `Range`: equal distributions for every FS challenge, chi-square under FS and
+//!   Fischlin challenges,
+//! * a simulator drawing z from the squares only: accepted, but caught by both tests, also
+//!   inside an `Or` when it is the branch the prover knows,
+//! * a simulator with a sign error: rejected by the driver's check,
+//! * on the real backends, every simulated transcript accepted.
+//!
+//!   cargo +nightly run --release --features backends,testing --example hvzk
+
+use core::marker::PhantomData;
+
+use fsr_core::protocols::and::And;
+use fsr_core::protocols::dleq::{self, Dleq};
+use fsr_core::protocols::linear::{self, Homomorphism, Linear};
+use fsr_core::protocols::or::{Either, Or};
+use fsr_core::protocols::range::{self, Opening, Range};
+use fsr_core::protocols::schnorr::Schnorr;
+use fsr_core::testing::hvzk::{exact, sample, Difference, Driver};
+use fsr_core::testing::toy::{Toy, Zq};
+use fsr_core::{FischlinParams, PrimeField, PrimeGroup, SigmaProtocol};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+type G = Toy<11>;
+type F = Zq<11>;
+
+/// Schnorr whose simulator only ever outputs z = u² (6 of the 11 responses): every transcript
+/// verifies, but z gives the simulation away.
+struct Squared<G>(PhantomData<G>);
+
+/// Schnorr whose simulator computes a = z·G + e·Y instead of z·G − e·Y.
+struct Flipped<G>(PhantomData<G>);
+
+macro_rules! schnorr_with_simulator {
+    ($name:ident, |$y:ident, $e:ident, $rng:ident| $sim:expr) => {
+        impl<G: PrimeGroup> SigmaProtocol for $name<G> {
+            type Statement = G;
+            type Witness = G::Scalar;
+            type Commitment = G;
+            type Challenge = G::Scalar;
+            type Response = G::Scalar;
+            type State = G::Scalar;
+
+            fn commit<R: RngCore + ?Sized>(y: &G, w: &G::Scalar, rng: &mut R) -> (G, G::Scalar) { Schnorr::<G>::commit(y, w, rng) }
+            fn respond(y: &G, w: &G::Scalar, r: &G::Scalar, e: &G::Scalar) -> G::Scalar { Schnorr::<G>::respond(y, w, r, e) }
+            fn verify(y: &G, a: &G, e: &G::Scalar, z: &G::Scalar) -> bool { Schnorr::<G>::verify(y, a, e, z) }
+            fn simulate<R: RngCore + ?Sized>($y: &G, $e: &G::Scalar, $rng: &mut R) -> (G, G::Scalar) { $sim }
+            fn extract(y: &G, a: &G, transcripts: &[(G::Scalar, G::Scalar)]) -> Option<G::Scalar> { Schnorr::<G>::extract(y, a, transcripts) }
+        }
+    };
+}
+
+schnorr_with_simulator!(Squared, |y, e, rng| {
+    let u = G::Scalar::random(rng);
+    let z = u * u;
+    (G::generator() * z - *y * *e, z)
+});
+schnorr_with_simulator!(Flipped, |y, e, rng| {
+    let z = G::Scalar::random(rng);
+    (G::generator() * z + *y * *e, z)
+});
+
+/// FS challenge bytes that reduce to k.
+fn fs_e(k: u64) -> Vec<u8> {
+    let mut e = vec![0u8; 16];
+    e[..8].copy_from_slice(&k.to_le_bytes());
+    e
+}
+
+/// `exact` for every FS challenge of Z_11.
+fn exact_all<P: SigmaProtocol<Challenge = F>>(x: &P::Statement, w: &P::Witness) -> Result<usize, Difference> {
+    (0..11).map(|k| exact::<P, 11>(Driver::Fs, x, w, &fs_e(k))).sum()
+}
+
+fn toy(rng: &mut StdRng) {
+    // Fischlin challenges of 2 bits: 4 values, so transcripts repeat often enough to count.
+    let fischlin = Driver::Fischlin(FischlinParams::new(16, 8).with_t(2));
+    // Nonzero witnesses (a zero one makes Y the identity, and every simulator right).
+    let (w1, w2) = (F::from_u64(3), F::from_u64(5));
+    let (y1, y2) = (G::generator() * w1, G::generator() * w2);
+    let h = G::generator() * F::from_u64(7);
+
+    let dleq_st = dleq::Statement { g: G::generator(), h, a: y1, b: h * w1 };
+    let pedersen = linear::Statement::image(Homomorphism::new(vec![vec![G::generator(), h]]), &[w1, w2]);
+    let bit = Opening { v: 1, s: w2 };
+    let range_st = range::Statement { h, n: 1, c: range::pedersen(h, bit.v, bit.s) };
+    let left = Either::Left(w1);
+    let right = Either::Right(w2);
+
+    // Exact: every transcript equally likely under prover and simulator, for each of the 11 challenges.
+    let exacts = [
+        ("Schnorr", exact_all::<Schnorr<G>>(&y1, &w1)),
+        ("DLEQ", exact_all::<Dleq<G>>(&dleq_st, &w1)),
+        ("Linear", exact_all::<Linear<G>>(&pedersen, &vec![w1, w2])),
+        ("And", exact_all::<And<Schnorr<G>, Schnorr<G>>>(&(y1, y2), &(w1, w2))),
+        ("Or (left witness)", exact_all::<Or<Schnorr<G>, Schnorr<G>>>(&(y1, y2), &left)),
+        ("Or (right witness)", exact_all::<Or<Schnorr<G>, Schnorr<G>>>(&(y1, y2), &right)),
+        ("Range", exact_all::<Range<G>>(&range_st, &bit)),
+    ];
+
+    // Chi-square, FS and Fischlin challenges, ~8 samples per transcript.
+    let chis = [
+        ("Schnorr (FS)", sample::<Schnorr<G>, _>(Driver::Fs, &y1, &w1, 8 * 121, rng)),
+        ("Schnorr (Fischlin)", sample::<Schnorr<G>, _>(fischlin, &y1, &w1, 8 * 44, rng)),
+        ("DLEQ (Fischlin)", sample::<Dleq<G>, _>(fischlin, &dleq_st, &w1, 8 * 44, rng)),
+        ("And (Fischlin)", sample::<And<Schnorr<G>, Schnorr<G>>, _>(fischlin, &(y1, y2), &(w1, w2), 8 * 484, rng)),
+        ("Or (Fischlin)", sample::<Or<Schnorr<G>, Schnorr<G>>, _>(fischlin, &(y1, y2), &right, 8 * 5324, rng)),
+    ];
+
+    // The squares-only simulator: on its own, and as the known branch of an Or (the unknown
+    // branch is simulated by the honest prover too, so there the bias is invisible).
+    let squared = exact::<Squared<G>, 11>(Driver::Fs, &y1, &w1, &fs_e(3));
+    let squared_chi = sample::<Squared<G>, _>(Driver::Fs, &y1, &w1, 8 * 121, rng);
+    let or_known = exact::<Or<Schnorr<G>, Squared<G>>, 11>(Driver::Fs, &(y1, y2), &right, &fs_e(3));
+    let or_unknown = exact::<Or<Schnorr<G>, Squared<G>>, 11>(Driver::Fs, &(y1, y2), &left, &fs_e(3));
+    let or_chi = sample::<Or<Schnorr<G>, Squared<G>>, _>(fischlin, &(y1, y2), &right, 8 * 5324, rng);
+    let flipped = exact::<Flipped<G>, 11>(Driver::Fs, &y1, &w1, &fs_e(3));
+    let flipped_chi = sample::<Flipped<G>, _>(fischlin, &y1, &w1, 64, rng);
+
+    for (what, result) in &exacts {
+        assert!(result.is_ok(), "Toy<11>: {what} exact: {result:?}");
+    }
+    for (what, report) in &chis {
+        assert!(report.passes(), "Toy<11>: {what} chi-square: {report:?}");
+    }
+    assert!(matches!(squared, Err(Difference::Differs { .. })), "Toy<11>: squared simulator exact: {squared:?}");
+    assert!(!squared_chi.passes(), "Toy<11>: squared simulator passes chi-square: {squared_chi:?}");
+    assert_eq!(squared_chi.rejected, [0, 0], "Toy<11>: squared simulator transcripts rejected");
+    assert!(matches!(or_known, Err(Difference::Differs { .. })), "Toy<11>: squared known Or branch exact: {or_known:?}");
+    assert!(or_unknown.is_ok(), "Toy<11>: squared unknown Or branch exact: {or_unknown:?}");
+    assert!(!or_chi.passes(), "Toy<11>: squared known Or branch passes chi-square: {or_chi:?}");
+    assert!(matches!(flipped, Err(Difference::Rejected { simulated: true, .. })), "Toy<11>: flipped simulator exact: {flipped:?}");
+    assert_eq!(flipped_chi.rejected[0], 0, "Toy<11>: honest transcripts rejected next to the flipped simulator");
+    assert!(flipped_chi.rejected[1] > 0, "Toy<11>: flipped simulator transcripts accepted");
+
+    println!(
+        "Toy<11>        exact and chi-square ok; squared simulator chi2 = {:.0} (critical {:.0}), \
+         in Or = {:.0} (critical {:.0}), caught squared / Or / flipped",
+        squared_chi.chi2, squared_chi.critical(), or_chi.chi2, or_chi.critical()
+    );
+}
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) {
+    let fischlin = Driver::Fischlin(FischlinParams::new(16, 8));
+    let (w1, w2) = (G::Scalar::random(rng), G::Scalar::random(rng));
+    let (y1, y2) = (G::generator() * w1, G::generator() * w2);
+    let h = G::generator() * G::Scalar::random(rng);
+    let dleq_st = dleq::Statement { g: G::generator(), h, a: y1, b: h * w1 };
+    let pedersen = linear::Statement::image(Homomorphism::new(vec![vec![G::generator(), h]]), &[w1, w2]);
+    let opening = Opening { v: rng.next_u64() & 0xff, s: w2 };
+    let range_st = range::Statement { h, n: 8, c: range::pedersen(h, opening.v, opening.s) };
+
+    // Every transcript distinct: only the acceptance counts say something here.
+    for driver in [Driver::Fs, fischlin] {
+        let rejected = [
+            ("Schnorr", sample::<Schnorr<G>, _>(driver, &y1, &w1, 32, rng).rejected),
+            ("DLEQ", sample::<Dleq<G>, _>(driver, &dleq_st, &w1, 32, rng).rejected),
+            ("Linear", sample::<Linear<G>, _>(driver, &pedersen, &vec![w1, w2], 32, rng).rejected),
+            ("And", sample::<And<Schnorr<G>, Schnorr<G>>, _>(driver, &(y1, y2), &(w1, w2), 32, rng).rejected),
+            ("Or", sample::<Or<Schnorr<G>, Schnorr<G>>, _>(driver, &(y1, y2), &Either::Left(w1), 32, rng).rejected),
+            ("Range", sample::<Range<G>, _>(driver, &range_st, &opening, 8, rng).rejected),
+        ];
+        for (what, rejected) in rejected {
+            assert_eq!(rejected, [0, 0], "{name}: {what} ({driver:?}) honest / simulated transcripts rejected");
+        }
+    }
+    let flipped = sample::<Flipped<G>, _>(Driver::Fs, &y1, &w1, 8, rng).rejected;
+    assert_eq!(flipped, [0, 8], "{name}: flipped simulator honest / simulated transcripts rejected");
+
+    println!("{name:<14} honest and simulated transcripts accepted (FS, Fischlin), flipped rejected");
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(25);
+    toy(&mut rng);
+
+    for_each_backend!(B, name => run::<B>(name, &mut rng));
+}
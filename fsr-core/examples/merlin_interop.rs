@@ -0,0 +1,101 @@
+//! `MerlinOracle` vs the `merlin` crate: published test transcripts, a long multi-round
+//! transcript, and a typed-`Transcript` Schnorr proof checked by a plain Merlin verifier
+//! (and the reverse).
+//!
+//!   cargo +nightly run --features merlin --example merlin_interop
+
+use fsr_core::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct G1(pub u64);
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scalar(pub u64);
+
+impl CanonicalEncode for G1 { fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.0.to_le_bytes()); } }
+impl CanonicalEncode for Scalar { fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.0.to_le_bytes()); } }
+impl Challenge for Scalar {
+    const BYTES: usize = 8;
+    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self {
+        let mut b = [0u8; 8]; b.copy_from_slice(&input[..8]); Scalar(u64::from_le_bytes(b))
+    }
+}
+impl G1 {
+    pub fn add(self, o: G1) -> G1 { G1(self.0.wrapping_add(o.0)) }
+    pub fn smul(self, s: Scalar) -> G1 { G1(self.0.wrapping_mul(s.0)) }
+}
+
+#[derive(Clone, Debug, FsrBindable)]
+#[bind(prefix = "Commit")]
+pub struct Commit { #[bind(ob = 0)] pub t: G1 }
+impl Message for Commit { const DIR: Direction = Direction::ProverToVerifier; const LABEL: &'static str = "Commit"; }
+
+declare_round!(R1 = [Commit]);
+
+fn hex(b: &[u8]) -> String { b.iter().map(|x| format!("{x:02x}")).collect() }
+
+fn main() -> Result<()> {
+    // 1) Merlin's simple test transcript (published challenge value).
+    let mut ours = MerlinOracle::new(b"test protocol");
+    ours.append_message(b"some label", b"some data");
+    let mut c = [0u8; 32];
+    ours.challenge_bytes(b"challenge", &mut c);
+    println!("simple transcript challenge = {}", hex(&c));
+    assert_eq!(hex(&c), "d5a21972d0d5fe320c0d263fac7fffb8145aa640af6e9bca177c03c7efcf0615");
+
+    // 2) Merlin's complex test: messages wrapping the sponge, 32 challenge rounds.
+    let mut ours = MerlinOracle::new(b"test protocol");
+    let mut theirs = ::merlin::Transcript::new(b"test protocol");
+    ours.append_message(b"step1", b"some data");
+    theirs.append_message(b"step1", b"some data");
+    let data = vec![99u8; 1024];
+    let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+    for _ in 0..32 {
+        ours.challenge_bytes(b"challenge", &mut a);
+        theirs.challenge_bytes(b"challenge", &mut b);
+        assert_eq!(a, b);
+        ours.append_message(b"bigdata", &data);
+        theirs.append_message(b"bigdata", &data);
+        ours.append_message(b"challengedata", &a);
+        theirs.append_message(b"challengedata", &b);
+    }
+    // Odd challenge lengths, including ones longer than the STROBE rate.
+    for len in [1usize, 17, 166, 500] {
+        let (mut a, mut b) = (vec![0u8; len], vec![0u8; len]);
+        ours.challenge_bytes(b"len", &mut a);
+        theirs.challenge_bytes(b"len", &mut b);
+        assert_eq!(a, b);
+    }
+    println!("complex transcript matches merlin = true");
+
+    // 3) Typed transcript proof, verified by a plain Merlin verifier.
+    let (g, x, r) = (G1(7), Scalar(0x23), Scalar(0x1111));
+    let y = g.smul(x);
+    let t = g.smul(r);
+    let tr: R1<MerlinOracle> = Transcript::new(MerlinOracle::new(b"schnorr-demo"));
+    let tr = tr.absorb::<{ Commit::OBLIG_MASK }, _>(Commit::LABEL, &Commit { t });
+    let (e, _tr) = tr.challenge::<Scalar>("e")?;
+    let z = Scalar(r.0.wrapping_add(e.0.wrapping_mul(x.0)));
+
+    // What `Transcript::absorb` appends for `Commit`: the field, then the message marker.
+    let mut v = ::merlin::Transcript::new(b"schnorr-demo");
+    v.append_message(Commit::LABEL_t.as_bytes(), &t.0.to_le_bytes());
+    v.append_message(Commit::LABEL.as_bytes(), b"");
+    let mut eb = [0u8; 8];
+    v.challenge_bytes(b"e", &mut eb);
+    let e_merlin = Scalar(u64::from_le_bytes(eb));
+    let ok = e_merlin == e && g.smul(z) == t.add(y.smul(e_merlin));
+    println!("typed proof accepted by merlin verifier = {ok}");
+    assert!(ok);
+
+    // 4) And the reverse: a Merlin prover's challenge reproduced through the typed transcript.
+    let mut p = ::merlin::Transcript::new(b"schnorr-demo");
+    p.append_message(Commit::LABEL_t.as_bytes(), &t.0.to_le_bytes());
+    p.append_message(Commit::LABEL.as_bytes(), b"");
+    p.challenge_bytes(b"e", &mut eb);
+    let tr: R1<MerlinOracle> = Transcript::new(MerlinOracle::new(b"schnorr-demo"));
+    let tr = tr.absorb::<{ Commit::OBLIG_MASK }, _>(Commit::LABEL, &Commit { t });
+    let (e_ours, _tr) = tr.challenge::<Scalar>("e")?;
+    println!("merlin challenge reproduced by typed transcript = {}", e_ours.0 == u64::from_le_bytes(eb));
+    assert_eq!(e_ours.0, u64::from_le_bytes(eb));
+    Ok(())
+}
@@ -0,0 +1,124 @@
+//! Poseidon random oracle over the BN254 and BLS12-381 scalar fields.
+//! * native field challenges for a toy linear relation over `Fr` (NOT a secure group),
+//! * canonical byte packing (splitting the same bytes differently changes the challenge),
+//! * the unchanged `FSOracle` / `FischlinOracle` pipelines on top of Poseidon.
+//!
+//!   cargo +nightly run --features poseidon --example poseidon_oracle
+
+use ark_crypto_primitives::sponge::Absorb as SpongeAbsorb;
+use ark_ff::PrimeField;
+use fsr_core::fs_proof::verify_fs;
+use fsr_core::{
+    verify_fischlin, FSOracle, FischlinOracle, FischlinParams, FischlinProof, FsProof, PoseidonOracle,
+    Result, TranscriptRuntime,
+};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+// ---------------- Toy group for the byte pipelines (same as schnorr_fischlin) ----------------
+const MOD_P: u64 = 2_147_483_647;
+const ORDER_Q: u64 = MOD_P - 1;
+
+fn modp(x: u128) -> u64 { (x % MOD_P as u128) as u64 }
+fn powmod(mut base: u64, mut exp: u64) -> u64 {
+    let mut acc = 1u64;
+    while exp > 0 {
+        if exp & 1 == 1 { acc = modp(acc as u128 * base as u128); }
+        base = modp(base as u128 * base as u128);
+        exp >>= 1;
+    }
+    acc
+}
+fn enc_u64(x: u64) -> Vec<u8> { x.to_le_bytes().to_vec() }
+fn dec_le_u64(bytes: &[u8]) -> u64 {
+    let mut v = 0u64;
+    for (i, &b) in bytes.iter().enumerate().take(8) { v |= (b as u64) << (8 * i); }
+    v
+}
+
+const G: u64 = 7;
+fn respond(r: u64, e: &[u8], w: u64) -> u64 {
+    (r + ((dec_le_u64(e) % ORDER_Q) as u128 * w as u128 % ORDER_Q as u128) as u64) % ORDER_Q
+}
+fn sigma_verify(y: u64, m: &[u8], e: &[u8], z: &[u8]) -> bool {
+    let (t, e, z) = (dec_le_u64(m), dec_le_u64(e) % ORDER_Q, dec_le_u64(z) % ORDER_Q);
+    powmod(G, z) == modp(t as u128 * powmod(y, e) as u128)
+}
+
+fn byte_pipelines<F: PrimeField + SpongeAbsorb>(name: &str, mk: impl Fn() -> PoseidonOracle<F>) -> Result<()> {
+    let w = 424_242u64;
+    let y = powmod(G, w);
+    let x = enc_u64(y);
+    let sid = b"poseidon-demo";
+    let mut rng = StdRng::seed_from_u64(5);
+
+    // FS
+    let mut fs = FSOracle::new(mk());
+    fs.absorb("mode", b"FS");
+    fs.absorb("x", &x);
+    fs.absorb("sid", sid);
+    let r = rng.next_u64() % ORDER_Q;
+    let m = enc_u64(powmod(G, r));
+    fs.absorb("m_i", &m);
+    let e = fs.derive_challenge("e_i", &[], 32);
+    let proof = FsProof { m: vec![m], z: vec![enc_u64(respond(r, &e, w))], rho: 1, b: 0 };
+    let fs_ok = verify_fs(FSOracle::new(mk()), x.clone(), sid, &proof, 32, |_i, m, e, z| sigma_verify(y, m, e, z));
+
+    // Fischlin
+    let params = FischlinParams::new(16, 8);
+    let mut oracle = FischlinOracle::new(mk(), params);
+    let proof = loop {
+        oracle.begin(&x, sid);
+        let rs: Vec<u64> = (0..params.rho).map(|_| rng.next_u64() % ORDER_Q).collect();
+        let ms: Vec<Vec<u8>> = rs.iter().map(|r| enc_u64(powmod(G, *r))).collect();
+        for m in &ms { oracle.push_first_message(m)?; }
+        oracle.seal_first_messages()?;
+        let found: Result<Vec<(Vec<u8>, Vec<u8>)>> = rs.iter().enumerate()
+            .map(|(i, r)| oracle.search_round(i as u32, |e| enc_u64(respond(*r, e, w))))
+            .collect();
+        if let Ok(ez) = found {
+            let (e, z) = ez.into_iter().unzip();
+            break FischlinProof { m: ms, e, z, b: params.b, rho: params.rho };
+        }
+    };
+    let fischlin_ok = verify_fischlin(mk(), params, &x, sid, &proof, |_i, m, e, z| sigma_verify(y, m, e, z));
+
+    println!("{name:>10}: FS ok = {fs_ok}, Fischlin ok = {fischlin_ok}");
+    assert!(fs_ok && fischlin_ok);
+    Ok(())
+}
+
+/// Toy linear relation y = a·w over `F`, with a native challenge e ∈ F.
+fn native_challenge<F: PrimeField + SpongeAbsorb>(name: &str, mk: impl Fn() -> PoseidonOracle<F>) {
+    let (a, w, r) = (F::from(3u64), F::from(123_456_789u64), F::from(987_654_321u64));
+    let (y, t) = (a * w, a * r);
+
+    let transcript = |t: F| {
+        let mut o = mk();
+        o.absorb_field("x", &[a, y]);
+        o.absorb_field("t", &[t]);
+        o.challenge_field("e")
+    };
+    let e = transcript(t);
+    let z = r + e * w;
+    let ok = a * z == t + transcript(t) * y;
+
+    // The same bytes split differently must not collide.
+    let split = |parts: &[&[u8]]| {
+        let mut o = mk();
+        for p in parts { o.absorb("m", p); }
+        o.challenge_field("e")
+    };
+    let distinct = split(&[b"ab", b"c"]) != split(&[b"a", b"bc"]) && split(&[b"abc"]) != split(&[b"abc", b""]);
+
+    println!("{name:>10}: native challenge relation ok = {ok}, packing injective = {distinct}");
+    assert!(ok && distinct);
+}
+
+fn main() -> Result<()> {
+    const DST: &[u8] = b"YavOracle/PoseidonDemo";
+    native_challenge("BN254", || PoseidonOracle::bn254(DST));
+    native_challenge("BLS12-381", || PoseidonOracle::bls12_381(DST));
+    byte_pipelines("BN254", || PoseidonOracle::bn254(DST))?;
+    byte_pipelines("BLS12-381", || PoseidonOracle::bls12_381(DST))?;
+    Ok(())
+}
@@ -0,0 +1,178 @@
+//! Schnorr and Chaum–Pedersen over Ristretto255 (`fsr_core::groups::ristretto`).
+//! * Schnorr through `proof!` with typed `RistrettoPoint` / `Scalar` fields,
+//! * Schnorr through `prove!` / `verify!` (FS, byte-level closures, 48-byte wide challenges),
+//! * Chaum–Pedersen (equality of discrete logs) through `proof!`,
+//! * strict decoding: non-canonical points and scalars are rejected.
+//!
+//!   cargo +nightly run --features ristretto --example ristretto_sigma
+
+use fsr_core::groups::ristretto::{RistrettoPoint, Scalar, BASEPOINT};
+use fsr_core::{
+    Absorb, CanonicalDecode, CanonicalEncode, Challenge, FsProof, HashOracle, Oracle, RandomOracle,
+    RecordingHashOracle, Result,
+};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+fn random_scalar(rng: &mut StdRng) -> Scalar {
+    let mut wide = [0u8; 64];
+    rng.fill_bytes(&mut wide);
+    Scalar::from_bytes_mod_order_wide(&wide)
+}
+
+fn enc<T: CanonicalEncode>(v: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    v.encode(&mut out);
+    out
+}
+
+fn dec<T: CanonicalDecode>(mut bytes: &[u8]) -> Option<T> {
+    let v = T::decode(&mut bytes)?;
+    bytes.is_empty().then_some(v)
+}
+
+// ---------------- Schnorr: y = x·B ----------------
+
+#[derive(Clone, Copy, Debug)]
+struct SchnorrPublic { y: RistrettoPoint }
+
+fsr_proof_dsl::proof! {
+    name: Schnorr;
+    domain: "YavOracle/Ristretto/Schnorr";
+    public: SchnorrPublic;
+    fields {
+        t: RistrettoPoint <= absorb    "commit.t";
+        e: Scalar         <= challenge "e";
+        z: Scalar         <= absorb    "resp.z";
+    }
+    replay {
+        bind "stmt.y" <- pub_in.y;
+        bind "commit.t" <- t;
+    }
+    check {{
+        BASEPOINT * z == t + pub_in.y * e
+    }}
+}
+
+// ---------------- Chaum–Pedersen: y1 = x·B, y2 = x·H ----------------
+
+#[derive(Clone, Copy, Debug)]
+struct DleqPublic { h: RistrettoPoint, y1: RistrettoPoint, y2: RistrettoPoint }
+
+fsr_proof_dsl::proof! {
+    name: Dleq;
+    domain: "YavOracle/Ristretto/ChaumPedersen";
+    public: DleqPublic;
+    fields {
+        t1: RistrettoPoint <= absorb    "commit.t1";
+        t2: RistrettoPoint <= absorb    "commit.t2";
+        e:  Scalar         <= challenge "e";
+        z:  Scalar         <= absorb    "resp.z";
+    }
+    replay {
+        bind "stmt.h" <- pub_in.h;
+        bind "stmt.y1" <- pub_in.y1;
+        bind "stmt.y2" <- pub_in.y2;
+        bind "commit.t1" <- t1;
+        bind "commit.t2" <- t2;
+    }
+    check {{
+        BASEPOINT * z == t1 + pub_in.y1 * e && pub_in.h * z == t2 + pub_in.y2 * e
+    }}
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(25519);
+    let x = random_scalar(&mut rng);
+
+    // 1) Schnorr via proof!
+    let pubc = SchnorrPublic { y: BASEPOINT * x };
+    let mut ro = RecordingHashOracle::new(HashOracle::new(b"YavOracle/Ristretto/Schnorr"));
+    ro.absorb_bytes("stmt.y", &enc(&pubc.y));
+    let r = random_scalar(&mut rng);
+    ro.absorb_bytes("commit.t", &enc(&(BASEPOINT * r)));
+    let e: Scalar = ro.challenge("e")?;
+    ro.absorb_bytes("resp.z", &enc(&(r + e * x)));
+    let proof = schnorr_prove(&ro.into_parts().1).expect("events decode");
+    let bytes = proof.encode();
+    let ok = schnorr_verify(&pubc, &proof) && schnorr_verify_bytes(&pubc, &bytes);
+    let wrong_key = SchnorrPublic { y: pubc.y + BASEPOINT };
+    let rejects = !schnorr_verify(&wrong_key, &proof);
+    println!("Schnorr (proof!): {} bytes, verify = {ok}, wrong key rejected = {rejects}", bytes.len());
+    assert!(ok && rejects);
+
+    // A non-canonical response (z + ℓ, i.e. z with its top bits set) must not decode.
+    let mut tampered = bytes.clone();
+    let z_at = tampered.len() - 32;
+    tampered[z_at + 31] |= 0xf0;
+    assert!(!schnorr_verify_bytes(&pubc, &tampered));
+
+    // 2) Schnorr via prove!/verify! (FS). Challenges are 48 bytes, reduced by `Scalar: Challenge`.
+    let statement = enc(&pubc.y);
+    let sid = b"ristretto-fs";
+    let chal = |e: &[u8]| <Scalar as Challenge>::from_oracle_bytes("e_i", e);
+    let fs: FsProof = fsr_proof_dsl::prove! {
+        transform = "fs",
+        oracle = HashOracle::new(b"YavOracle/Ristretto/FS"),
+        rho = 1u16,
+        b = 0u8,
+        statement = statement.clone(),
+        sid = sid,
+        challenge_len = <Scalar as Challenge>::BYTES,
+        first = |_i| {
+            let r = random_scalar(&mut rng);
+            (enc(&(BASEPOINT * r)), r)
+        },
+        respond = |_i: usize, e_bytes: &[u8], r: &Scalar| enc(&(r + chal(e_bytes) * x))
+    }?;
+    let fs_ok = fsr_proof_dsl::verify! {
+        transform = "fs",
+        oracle = HashOracle::new(b"YavOracle/Ristretto/FS"),
+        statement = &statement,
+        sid = sid,
+        proof = &fs,
+        challenge_len = <Scalar as Challenge>::BYTES,
+        sigma_verify = |_i, m_bytes, e_bytes, z_bytes| {
+            match (dec::<RistrettoPoint>(m_bytes), dec::<Scalar>(z_bytes)) {
+                (Some(t), Some(z)) => BASEPOINT * z == t + pubc.y * chal(e_bytes),
+                _ => false,
+            }
+        }
+    };
+    println!("Schnorr (prove!/verify! FS) verify = {fs_ok}");
+    assert!(fs_ok);
+
+    // 3) Chaum–Pedersen via proof!. H is a hash-to-group point with unknown discrete log.
+    let mut h_ro = HashOracle::new(b"YavOracle/Ristretto/Generators");
+    let h_bytes: [u8; 64] = h_ro.H_xof("H", b"chaum-pedersen", 64).try_into().unwrap();
+    let h = RistrettoPoint::from_uniform_bytes(&h_bytes);
+    let dleq_pub = DleqPublic { h, y1: BASEPOINT * x, y2: h * x };
+
+    let mut ro = RecordingHashOracle::new(HashOracle::new(b"YavOracle/Ristretto/ChaumPedersen"));
+    for (label, p) in [("stmt.h", dleq_pub.h), ("stmt.y1", dleq_pub.y1), ("stmt.y2", dleq_pub.y2)] {
+        ro.absorb_bytes(label, &enc(&p));
+    }
+    let r = random_scalar(&mut rng);
+    ro.absorb_bytes("commit.t1", &enc(&(BASEPOINT * r)));
+    ro.absorb_bytes("commit.t2", &enc(&(h * r)));
+    let e: Scalar = ro.challenge("e")?;
+    ro.absorb_bytes("resp.z", &enc(&(r + e * x)));
+    let proof = dleq_prove(&ro.into_parts().1).expect("events decode");
+    let ok = dleq_verify_bytes(&dleq_pub, &proof.encode());
+    // Different discrete logs (y2 = (x+1)·H) must not verify.
+    let unequal = DleqPublic { y2: h * (x + Scalar::ONE), ..dleq_pub };
+    let rejects = !dleq_verify(&unequal, &proof);
+    println!("Chaum–Pedersen (proof!) verify = {ok}, unequal logs rejected = {rejects}");
+    assert!(ok && rejects);
+
+    // 4) Strict decoding.
+    let mut non_canonical_point = [0u8; 32];
+    non_canonical_point[0] = 1; // s = 1 is "negative" in Ristretto and has no valid decoding
+    assert!(dec::<RistrettoPoint>(&non_canonical_point).is_none());
+    assert!(dec::<RistrettoPoint>(&[0xff; 32]).is_none()); // s ≥ p
+    assert!(dec::<Scalar>(&[0xff; 32]).is_none()); // ≥ ℓ
+    assert_eq!(dec::<Scalar>(&enc(&x)), Some(x));
+    assert_eq!(dec::<RistrettoPoint>(&enc(&dleq_pub.y2)), Some(dleq_pub.y2));
+    println!("non-canonical encodings rejected = true");
+
+    Ok(())
+}
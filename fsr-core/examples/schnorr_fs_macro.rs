@@ -118,5 +118,43 @@ fn main() -> Result<()> {
 
     println!("--- Schnorr FS verifier ---\n{}\n", verifier_src);
 
+    // Wider challenges (e.g. 64 bytes for wide reduction into a 256-bit scalar field).
+    let wide: FsProof = fsr_proof_dsl::prove! {
+        transform = "fs",
+        oracle = HashOracle::new(b"YavOracle/FS-Macro"),
+        rho = rho,
+        b = b_bits,
+        statement = statement.clone(),
+        sid = sid,
+        challenge_len = 64,
+        first = |_i| {
+            let r = Scalar(rng.next_u64() % ORDER_Q);
+            (enc_u64(schnorr_prover_first(&pubc, r).t.0), r)
+        },
+        respond = |_i: usize, e_bytes: &[u8], r_i: &Scalar| {
+            assert_eq!(e_bytes.len(), 64);
+            let e = Scalar(dec_le_u64(e_bytes) % ORDER_Q);
+            enc_u64(schnorr_prover_second(&pubc, *r_i, e, wit.w).z.0)
+        }
+    }?;
+    let verify_wide = |len: usize| fsr_proof_dsl::verify! {
+        transform = "fs",
+        oracle = HashOracle::new(b"YavOracle/FS-Macro"),
+        statement = &statement_for_verify,
+        sid = sid,
+        proof = &wide,
+        challenge_len = len,
+        sigma_verify = |_i, m_bytes, e_bytes, z_bytes| {
+            let m = FirstMsg { t: G1(dec_le_u64(m_bytes)) };
+            let e = Scalar(dec_le_u64(e_bytes) % ORDER_Q);
+            let z = Resp { z: Scalar(dec_le_u64(z_bytes) % ORDER_Q) };
+            schnorr_verify(&pubc, &m, e, &z)
+        }
+    };
+    // The length is domain separated: a verifier expecting 32-byte challenges rejects.
+    let (ok64, ok32) = (verify_wide(64), verify_wide(32));
+    println!("FS verify (64-byte challenge) = {}, with 32-byte verifier = {}", ok64, ok32);
+    assert!(ok && ok64 && !ok32);
+
     Ok(())
 }
@@ -23,10 +23,11 @@ impl CanonicalDecode for Scalar {
     }
 }
 // Challenge
+// Scalars are taken mod 2^64 (wrapping arithmetic), so 64-bit truncation is exactly uniform.
 impl fsr_core::Challenge for Scalar {
-    const BYTES: usize = 8;
-    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self {
-        let mut b = [0u8; 8]; b.copy_from_slice(&input[..8]); Scalar(u64::from_le_bytes(b))
+    const BYTES: usize = TruncatedBits::<64>::BYTES;
+    fn from_oracle_bytes(label: &str, input: &[u8]) -> Self {
+        Scalar(TruncatedBits::<64>::from_oracle_bytes(label, input).0)
     }
 }
 
@@ -116,7 +117,9 @@ proof! {
       z1: Scalar <= absorb   Response::LABEL_z;
     }
     replay {
+      // exactly what the prover's transcript absorbed: bound fields, message marker, shape label
       bind Commit::LABEL_t <- t1;
+      bind Commit::LABEL;
       bind Commit::MSG_LABEL;
     }
     check {
@@ -133,7 +136,11 @@ proof! {
       z2: Scalar <= absorb   Response::LABEL_z;
     }
     replay {
+      // round 1's response was absorbed before this round's commitment
+      bind Response::LABEL_z <- proof.z1;
+      bind Response::LABEL;
       bind Commit::LABEL_t <- t2;
+      bind Commit::LABEL;
       bind Commit::MSG_LABEL;
     }
     check {
@@ -36,14 +36,13 @@ fn main() -> fsr_core::Result<()> {
         #[inline] fn mul(self, other: Scalar) -> Scalar { Scalar(modp_u64(self.0 as u128 * other.0 as u128)) }
     }
     impl CanonicalEncode for Scalar { fn encode(&self, out: &mut Vec<u8>) { put_le_u64(self.0, out) } }
+    // Unbiased: |p| + 128 oracle bits reduced mod p.
+    type ScalarReduction = WideReduction<fsr_core::challenge::Mod64<MOD_P>>;
     impl Challenge for Scalar {
-        fn from_oracle_bytes(_label: &str, bytes: &[u8]) -> Self {
-            use core::hash::{Hash, Hasher};
-            let mut s = std::collections::hash_map::DefaultHasher::new();
-            bytes.hash(&mut s);
-            Scalar(modp_u64(s.finish() as u128))
+        fn from_oracle_bytes(label: &str, bytes: &[u8]) -> Self {
+            Scalar(ScalarReduction::from_oracle_bytes(label, bytes).0.as_u64())
         }
-        const BYTES: usize = 32;
+        const BYTES: usize = ScalarReduction::BYTES;
     }
 
     #[derive(Clone, Debug)]
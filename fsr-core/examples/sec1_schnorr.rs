@@ -0,0 +1,117 @@
+//! Schnorr over secp256k1 and P-256 (`fsr_core::groups::{secp256k1, p256}`) with the typed
+//! `Transcript`: the public key, commitment and response are `FsrBindable` messages holding the
+//! curve's own `ProjectivePoint` / `Scalar`, bound as SEC1 compressed points and big-endian scalars.
+//!
+//!   cargo +nightly run --features secp256k1,p256,sha2 --example sec1_schnorr
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// One Schnorr instantiation per curve (`FsrBindable` messages are concrete types).
+macro_rules! schnorr_on {
+    ($m:ident, $name:literal, $group:path) => {
+        mod $m {
+            use fsr_core::*;
+            use rand::rngs::StdRng;
+            use $group::{ProjectivePoint, Scalar, POINT_BYTES};
+
+            pub const NAME: &str = $name;
+            const DST: &[u8] = concat!("YavOracle/Schnorr/", $name).as_bytes();
+
+            #[derive(Clone, Debug, FsrBindable)]
+            #[bind(prefix = "Statement")]
+            pub struct Statement { #[bind(ob = 0)] pub y: ProjectivePoint }
+            impl Message for Statement {
+                const DIR: Direction = Direction::ProverToVerifier;
+                const LABEL: &'static str = "Statement";
+            }
+
+            #[derive(Clone, Debug, FsrBindable)]
+            #[bind(prefix = "Commit")]
+            pub struct Commit { #[bind(ob = 1)] pub t: ProjectivePoint }
+            impl Message for Commit {
+                const DIR: Direction = Direction::ProverToVerifier;
+                const LABEL: &'static str = "Commit";
+            }
+
+            #[derive(Clone, Debug, FsrBindable)]
+            #[bind(prefix = "Response")]
+            pub struct Response { pub z: Scalar }
+            impl Message for Response {
+                const DIR: Direction = Direction::ProverToVerifier;
+                const LABEL: &'static str = "Response";
+            }
+
+            declare_round!(R1 = [Statement, Commit]);
+
+            /// The challenge both sides derive for (y, t).
+            fn challenge(y: ProjectivePoint, t: ProjectivePoint) -> Result<Scalar> {
+                let tr: R1<Sha256Oracle> = Transcript::new(Sha256Oracle::new(DST));
+                let tr = tr.absorb::<{ Statement::OBLIG_MASK }, _>(Statement::LABEL, &Statement { y });
+                let tr = tr.absorb::<{ Commit::OBLIG_MASK }, _>(Commit::LABEL, &Commit { t });
+                Ok(tr.challenge::<Scalar>("e")?.0)
+            }
+
+            /// Proof bytes: t (33) || z (32).
+            pub fn prove(x: Scalar, rng: &mut StdRng) -> Result<(ProjectivePoint, Vec<u8>)> {
+                let y = ProjectivePoint::GENERATOR * x;
+                let r = Scalar::random(&mut *rng);
+                let t = ProjectivePoint::GENERATOR * r;
+                let e = challenge(y, t)?;
+                let z = r + e * x;
+                let mut proof = Vec::new();
+                Commit { t }.t.encode(&mut proof);
+                Response { z }.z.encode(&mut proof);
+                Ok((y, proof))
+            }
+
+            pub fn verify(y: ProjectivePoint, mut proof: &[u8]) -> bool {
+                let (Some(t), Some(z)) = (ProjectivePoint::decode(&mut proof), Scalar::decode(&mut proof)) else {
+                    return false;
+                };
+                if !proof.is_empty() { return false; }
+                match challenge(y, t) {
+                    Ok(e) => ProjectivePoint::GENERATOR * z == t + y * e,
+                    Err(_) => false,
+                }
+            }
+
+            pub fn run(rng: &mut StdRng) -> Result<()> {
+                let x = Scalar::random(&mut *rng);
+                let (y, proof) = prove(x, rng)?;
+                let ok = verify(y, &proof);
+                let wrong_key = !verify(y + ProjectivePoint::GENERATOR, &proof);
+                println!("{NAME:>9}: Schnorr ({} bytes) verify = {ok}, wrong key rejected = {wrong_key}", proof.len());
+                assert!(ok && wrong_key);
+
+                // Strict SEC1 / scalar decoding.
+                let mut bad = proof.clone();
+                bad[0] = 0x04; // uncompressed tag with a compressed length
+                assert!(!verify(y, &bad));
+                let mut bad = proof.clone();
+                bad[1..POINT_BYTES].fill(0xff); // x ≥ p
+                assert!(!verify(y, &bad));
+                let mut bad = proof.clone();
+                bad[POINT_BYTES..].fill(0xff); // z ≥ n
+                assert!(!verify(y, &bad));
+                let mut identity = Vec::new();
+                ProjectivePoint::IDENTITY.encode(&mut identity);
+                assert!(ProjectivePoint::decode(&mut identity.as_slice()).is_none());
+                let mut y_bytes = Vec::new();
+                y.encode(&mut y_bytes);
+                assert_eq!(ProjectivePoint::decode(&mut y_bytes.as_slice()), Some(y));
+                println!("{NAME:>9}: non-canonical encodings rejected = true");
+                Ok(())
+            }
+        }
+    };
+}
+
+schnorr_on!(k1, "secp256k1", fsr_core::groups::secp256k1);
+schnorr_on!(r1, "P-256", fsr_core::groups::p256);
+
+fn main() -> fsr_core::Result<()> {
+    let mut rng = StdRng::seed_from_u64(256);
+    k1::run(&mut rng)?;
+    r1::run(&mut rng)?;
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Sigma AND whose `bind` forgets branch 0 (the combinator counterpart of
+//! `sigma_and_dsl_fs_bug` / `sigma_and_dsl_fischlin_bug`, which miss the `c_0` absorb).
+//! `ForgetfulAnd` delegates everything to `And<Schnorr, Schnorr>` and still lists both branches
+//! in `require`, but binds only `and.1`. The DSL refuses such a bind at compile time; the
+//! drivers check the label paths at run time: the prover returns an error before any challenge
+//! is derived, and the verifier rejects even honest `And` proofs under this binding.
+//!
+//!   cargo +nightly run --features backends --example sigma_and_combinator_bug
+
+use core::marker::PhantomData;
+
+use fsr_core::protocols::and::And;
+use fsr_core::protocols::schnorr::Schnorr;
+use fsr_core::sigma::{prove_fischlin, prove_fs, verify_fischlin, verify_fs, Binder, Labels};
+use fsr_core::{FischlinParams, HashOracle, PrimeField, PrimeGroup, ProveError, Result, SigmaProtocol};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+const FS_DST: &[u8] = b"YavOracle/SigmaAND/Combinator/FS";
+const FISCHLIN_DST: &[u8] = b"YavOracle/SigmaAND/Combinator/Fischlin";
+
+type And2<G> = And<Schnorr<G>, Schnorr<G>>;
+
+struct ForgetfulAnd<G>(PhantomData<G>);
+
+impl<G: PrimeGroup> SigmaProtocol for ForgetfulAnd<G> {
+    type Statement = (G, G);
+    type Witness = (G::Scalar, G::Scalar);
+    type Commitment = (G, G);
+    type Challenge = G::Scalar;
+    type Response = (G::Scalar, G::Scalar);
+    type State = (G::Scalar, G::Scalar);
+
+    fn commit<R: RngCore + ?Sized>(x: &(G, G), w: &Self::Witness, rng: &mut R) -> ((G, G), Self::State) {
+        And2::<G>::commit(x, w, rng)
+    }
+    fn respond(x: &(G, G), w: &Self::Witness, state: &Self::State, e: &G::Scalar) -> Self::Response {
+        And2::<G>::respond(x, w, state, e)
+    }
+    fn verify(x: &(G, G), a: &(G, G), e: &G::Scalar, z: &Self::Response) -> bool {
+        And2::<G>::verify(x, a, e, z)
+    }
+    fn simulate<R: RngCore + ?Sized>(x: &(G, G), e: &G::Scalar, rng: &mut R) -> ((G, G), Self::Response) {
+        And2::<G>::simulate(x, e, rng)
+    }
+    fn extract(x: &(G, G), a: &(G, G), transcripts: &[(G::Scalar, Self::Response)]) -> Option<Self::Witness> {
+        And2::<G>::extract(x, a, transcripts)
+    }
+    fn require(x: &(G, G), labels: &mut Labels) {
+        And2::<G>::require(x, labels)
+    }
+    fn bind(x: &(G, G), a: &(G, G), binder: &mut Binder<'_>) {
+        // BUG: only bind branch 1
+        binder.scope("and.1", |b| Schnorr::<G>::bind(&x.1, &a.1, b));
+    }
+}
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) -> Result<()> {
+    let sid = b"sigma-and-combinator-bug";
+    let w = (G::Scalar::random(rng), G::Scalar::random(rng));
+    let x = (G::generator() * w.0, G::generator() * w.1);
+    let params = FischlinParams::new(16, 8);
+    let unbound = Err(ProveError::Malformed("sigma: bound commitments do not match the required labels"));
+
+    let fs_refused = prove_fs::<ForgetfulAnd<G>, _, _>(HashOracle::new(FS_DST), &x, &w, sid, rng).map(|_| ()) == unbound;
+    let fischlin_refused =
+        prove_fischlin::<ForgetfulAnd<G>, _, _>(HashOracle::new(FISCHLIN_DST), params, &x, &w, sid, rng).map(|_| ()) == unbound;
+
+    let honest_fs = prove_fs::<And2<G>, _, _>(HashOracle::new(FS_DST), &x, &w, sid, rng)?;
+    let honest_fischlin = prove_fischlin::<And2<G>, _, _>(HashOracle::new(FISCHLIN_DST), params, &x, &w, sid, rng)?;
+    let fs_rejected = !verify_fs::<ForgetfulAnd<G>, _>(HashOracle::new(FS_DST), &x, sid, &honest_fs);
+    let fischlin_rejected = !verify_fischlin::<ForgetfulAnd<G>, _>(HashOracle::new(FISCHLIN_DST), params, &x, sid, &honest_fischlin);
+
+    println!(
+        "{name:<14} prover refused (FS / Fischlin) = {fs_refused} / {fischlin_refused}, \
+         verifier rejects (FS / Fischlin) = {fs_rejected} / {fischlin_rejected}"
+    );
+    assert!(fs_refused, "{name}: FS prover accepted an unbound branch");
+    assert!(fischlin_refused, "{name}: Fischlin prover accepted an unbound branch");
+    assert!(fs_rejected, "{name}: FS verifier accepted without the branch binding");
+    assert!(fischlin_rejected, "{name}: Fischlin verifier accepted without the branch binding");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(1616);
+
+    for_each_backend!(G, name => run::<G>(name, &mut rng)?);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Sigma AND through the `protocols::and` combinators instead of hand-written shared challenges
+//! (compare `sigma_and_dsl_fs_ok` / `sigma_and_dsl_fischlin_ok`): knowledge of w0, w1 with
+//! y0 = w0·G and y1 = w1·G, proven with the generic FS and Fischlin drivers on every enabled
+//! backend. Also checks
+//! * composable labels: nested `And`s bind every Schnorr commitment under its own path,
+//! * `AndN` over three statements,
+//! * `simulate` / `extract` of the composition,
+//! * proofs for swapped statements or with swapped branch commitments are rejected.
+//!
+//!   cargo +nightly run --features backends --example sigma_and_combinator_ok
+
+use fsr_core::protocols::and::{And, AndN};
+use fsr_core::protocols::schnorr::Schnorr;
+use fsr_core::sigma::{decode_exact, encode, prove_fischlin, prove_fs, verify_fischlin, verify_fs, Labels};
+use fsr_core::{FischlinParams, HashOracle, PrimeField, PrimeGroup, Result, SigmaProtocol};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+const FS_DST: &[u8] = b"YavOracle/SigmaAND/Combinator/FS";
+const FISCHLIN_DST: &[u8] = b"YavOracle/SigmaAND/Combinator/Fischlin";
+
+type And2<G> = And<Schnorr<G>, Schnorr<G>>;
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) -> Result<()> {
+    let sid = b"sigma-and-combinator-ok";
+    let (w0, w1) = (G::Scalar::random(rng), G::Scalar::random(rng));
+    let x = (G::generator() * w0, G::generator() * w1);
+    let w = (w0, w1);
+    let swapped = (x.1, x.0);
+
+    // FS and Fischlin over the binary AND.
+    let fs = prove_fs::<And2<G>, _, _>(HashOracle::new(FS_DST), &x, &w, sid, rng)?;
+    let fs_ok = verify_fs::<And2<G>, _>(HashOracle::new(FS_DST), &x, sid, &fs);
+    let fs_swapped = verify_fs::<And2<G>, _>(HashOracle::new(FS_DST), &swapped, sid, &fs);
+    let params = FischlinParams::new(16, 8);
+    let fischlin = prove_fischlin::<And2<G>, _, _>(HashOracle::new(FISCHLIN_DST), params, &x, &w, sid, rng)?;
+    let fischlin_ok = verify_fischlin::<And2<G>, _>(HashOracle::new(FISCHLIN_DST), params, &x, sid, &fischlin);
+    let fischlin_swapped = verify_fischlin::<And2<G>, _>(HashOracle::new(FISCHLIN_DST), params, &swapped, sid, &fischlin);
+
+    // Swapping the branch commitments inside m keeps m well-formed but breaks both branches.
+    let mut tampered = fs.clone();
+    let (a0, a1) = decode_exact::<(G, G)>(&fs.m[0]).unwrap();
+    tampered.m[0] = encode(&(a1, a0));
+    let tamper_ok = !verify_fs::<And2<G>, _>(HashOracle::new(FS_DST), &x, sid, &tampered);
+
+    // Nesting: (y0 ∧ y1) ∧ y0, every commitment under its own label path.
+    type Nested<G> = And<And2<G>, Schnorr<G>>;
+    let nested_x = (x, x.0);
+    let mut labels = Labels::new();
+    Nested::<G>::require(&nested_x, &mut labels);
+    let labels_ok = labels.as_slice() == ["and.0/and.0", "and.0/and.1", "and.1"];
+    let nested = prove_fs::<Nested<G>, _, _>(HashOracle::new(FS_DST), &nested_x, &(w, w0), sid, rng)?;
+    let nested_ok = verify_fs::<Nested<G>, _>(HashOracle::new(FS_DST), &nested_x, sid, &nested);
+
+    // n-ary AND.
+    let ws: Vec<G::Scalar> = (0..3).map(|_| G::Scalar::random(rng)).collect();
+    let xs: Vec<G> = ws.iter().map(|w| G::generator() * *w).collect();
+    let n_fs = prove_fs::<AndN<Schnorr<G>>, _, _>(HashOracle::new(FS_DST), &xs, &ws, sid, rng)?;
+    let n_fischlin = prove_fischlin::<AndN<Schnorr<G>>, _, _>(HashOracle::new(FISCHLIN_DST), params, &xs, &ws, sid, rng)?;
+    let n_fs_ok = verify_fs::<AndN<Schnorr<G>>, _>(HashOracle::new(FS_DST), &xs, sid, &n_fs);
+    let n_fischlin_ok = verify_fischlin::<AndN<Schnorr<G>>, _>(HashOracle::new(FISCHLIN_DST), params, &xs, sid, &n_fischlin);
+    let n_dropped = verify_fs::<AndN<Schnorr<G>>, _>(HashOracle::new(FS_DST), &xs[..2].to_vec(), sid, &n_fs);
+
+    // simulate / extract compose branch-wise.
+    let e1 = G::Scalar::random(rng);
+    let (a_sim, z_sim) = And2::<G>::simulate(&x, &e1, rng);
+    let sim_ok = And2::<G>::verify(&x, &a_sim, &e1, &z_sim);
+    let (a, st) = And2::<G>::commit(&x, &w, rng);
+    let e2 = e1 + G::Scalar::one();
+    let transcripts = [(e1, And2::<G>::respond(&x, &w, &st, &e1)), (e2, And2::<G>::respond(&x, &w, &st, &e2))];
+    let extract_ok = And2::<G>::extract(&x, &a, &transcripts) == Some(w);
+    let extract_one = And2::<G>::extract(&x, &a, &transcripts[..1]);
+
+    println!(
+        "{name:<14} FS = {fs_ok}, Fischlin = {fischlin_ok}, nested = {nested_ok}, AndN = {n_fs_ok} / {n_fischlin_ok}, \
+         tampered rejected = {tamper_ok}, simulate = {sim_ok}, extract = {extract_ok}"
+    );
+    assert!(fs_ok, "{name}: FS proof rejected");
+    assert!(!fs_swapped, "{name}: FS proof accepted with the statements swapped");
+    assert!(fischlin_ok, "{name}: Fischlin proof rejected");
+    assert!(!fischlin_swapped, "{name}: Fischlin proof accepted with the statements swapped");
+    assert!(labels_ok, "{name}: nested label paths are {:?}", labels.as_slice());
+    assert!(nested_ok, "{name}: nested AND proof rejected");
+    assert!(n_fs_ok, "{name}: AndN FS proof rejected");
+    assert!(n_fischlin_ok, "{name}: AndN Fischlin proof rejected");
+    assert!(!n_dropped, "{name}: AndN proof accepted for a statement with a branch dropped");
+    assert!(tamper_ok, "{name}: swapped branch commitments accepted");
+    assert!(sim_ok, "{name}: simulated transcript rejected");
+    assert!(extract_ok, "{name}: extract did not recover the witness");
+    assert!(extract_one.is_none(), "{name}: extract accepted a single transcript");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(16);
+
+    for_each_backend!(G, name => run::<G>(name, &mut rng)?);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! `SigmaProtocol` + generic drivers: `protocols::schnorr::Schnorr<G>` proven and verified with
+//! `sigma::{prove_fs, verify_fs, prove_fischlin, verify_fischlin}` on every backend,
+//! with no per-protocol byte handling. Also checks that
+//! * driver FS proofs verify under `verify!` with a `bind_commitment` hook (same transcript),
+//! * `simulate` gives accepting transcripts for any challenge,
+//! * `extract` recovers the witness from two transcripts with one commitment,
+//! * tampered or non-canonical proofs are rejected.
+//!
+//!   cargo +nightly run --features backends --example sigma_drivers
+
+use fsr_core::protocols::schnorr::Schnorr;
+use fsr_core::sigma::{bind_commitment, decode_exact, prove_fischlin, prove_fs, verify_fischlin, verify_fs};
+use fsr_core::{Challenge, FSOracle, FischlinParams, HashOracle, PrimeField, PrimeGroup, Result, SigmaProtocol};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+const FS_DST: &[u8] = b"YavOracle/SigmaDrivers/FS";
+const FISCHLIN_DST: &[u8] = b"YavOracle/SigmaDrivers/Fischlin";
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) -> Result<()> {
+    let sid = b"sigma-drivers";
+    let w = G::Scalar::random(rng);
+    let y = G::generator() * w;
+    let other = y + G::generator();
+
+    // FS
+    let fs = prove_fs::<Schnorr<G>, _, _>(HashOracle::new(FS_DST), &y, &w, sid, rng)?;
+    let fs_ok = verify_fs::<Schnorr<G>, _>(HashOracle::new(FS_DST), &y, sid, &fs);
+    let fs_other_statement = verify_fs::<Schnorr<G>, _>(HashOracle::new(FS_DST), &other, sid, &fs);
+    let fs_other_sid = verify_fs::<Schnorr<G>, _>(HashOracle::new(FS_DST), &y, b"other-sid", &fs);
+    let macro_ok = fsr_proof_dsl::verify! {
+        transform = "fs",
+        oracle = HashOracle::new(FS_DST),
+        statement = &fsr_core::sigma::encode(&y),
+        sid = sid,
+        proof = &fs,
+        challenge_len = <G::Scalar as Challenge>::BYTES,
+        bind = |o: &mut FSOracle<HashOracle>, _i: usize, m: &[u8]| {
+            if let Some(a) = decode_exact::<G>(m) { let _ = bind_commitment::<Schnorr<G>>(o, &y, &a); }
+        },
+        sigma_verify = |_i, m, e, z| {
+            let e = <G::Scalar as Challenge>::from_oracle_bytes("e_i", e);
+            match (decode_exact::<G>(m), decode_exact::<G::Scalar>(z)) {
+                (Some(a), Some(z)) => Schnorr::<G>::verify(&y, &a, &e, &z),
+                _ => false,
+            }
+        }
+    };
+
+    // Fischlin
+    let params = FischlinParams::new(16, 8);
+    let fischlin = prove_fischlin::<Schnorr<G>, _, _>(HashOracle::new(FISCHLIN_DST), params, &y, &w, sid, rng)?;
+    let fischlin_ok = verify_fischlin::<Schnorr<G>, _>(HashOracle::new(FISCHLIN_DST), params, &y, sid, &fischlin);
+    let fischlin_other_statement = verify_fischlin::<Schnorr<G>, _>(HashOracle::new(FISCHLIN_DST), params, &other, sid, &fischlin);
+
+    // Tampering: a response with trailing bytes, and a swapped first message.
+    let mut padded = fs.clone();
+    padded.z[0].push(0);
+    let mut swapped = fischlin.clone();
+    swapped.m.swap(0, 1);
+    let padded_ok = verify_fs::<Schnorr<G>, _>(HashOracle::new(FS_DST), &y, sid, &padded);
+    let swapped_ok = verify_fischlin::<Schnorr<G>, _>(HashOracle::new(FISCHLIN_DST), params, &y, sid, &swapped);
+    let tamper_ok = !padded_ok && !swapped_ok;
+
+    // simulate / extract
+    let e1 = G::Scalar::random(rng);
+    let (a_sim, z_sim) = Schnorr::<G>::simulate(&y, &e1, rng);
+    let sim_ok = Schnorr::<G>::verify(&y, &a_sim, &e1, &z_sim);
+    let (a, r) = Schnorr::<G>::commit(&y, &w, rng);
+    let e2 = e1 + G::Scalar::one();
+    let transcripts = [(e1, Schnorr::<G>::respond(&y, &w, &r, &e1)), (e2, Schnorr::<G>::respond(&y, &w, &r, &e2))];
+    let extract_ok = Schnorr::<G>::extract(&y, &a, &transcripts) == Some(w);
+    let extract_repeated = Schnorr::<G>::extract(&y, &a, &[transcripts[0], transcripts[0]]);
+
+    println!(
+        "{name:<14} FS = {fs_ok} (verify! = {macro_ok}), Fischlin = {fischlin_ok}, tampered rejected = {tamper_ok}, \
+         simulate = {sim_ok}, extract = {extract_ok}"
+    );
+    assert!(fs_ok, "{name}: FS proof rejected");
+    assert!(!fs_other_statement, "{name}: FS proof accepted for another statement");
+    assert!(!fs_other_sid, "{name}: FS proof accepted under another sid");
+    assert!(macro_ok, "{name}: driver FS proof rejected by verify!");
+    assert!(fischlin_ok, "{name}: Fischlin proof rejected");
+    assert!(!fischlin_other_statement, "{name}: Fischlin proof accepted for another statement");
+    assert!(!padded_ok, "{name}: FS response with a trailing byte accepted");
+    assert!(!swapped_ok, "{name}: Fischlin proof with swapped first messages accepted");
+    assert!(sim_ok, "{name}: simulated transcript rejected");
+    assert!(extract_ok, "{name}: extract did not recover the witness");
+    assert!(extract_repeated.is_none(), "{name}: extract accepted a repeated challenge");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(15);
+
+    for_each_backend!(G, name => run::<G>(name, &mut rng)?);
+
+    Ok(())
+}
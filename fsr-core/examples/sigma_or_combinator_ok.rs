@@ -0,0 +1,107 @@
+//! Sigma OR through `protocols::or::Or` instead of hand-written challenge splitting (compare
+//! `sigma_or_fs_ok` / `sigma_or_dsl_fischlin_ok`): knowledge of w with y0 = w·G or y1 = w·G,
+//! proven with either witness under the generic FS and Fischlin drivers on every backend.
+//! Also checks
+//! * an OR with an AND branch, both commitments of the AND bound under `or.1/and.{0,1}`,
+//! * a changed split e0 or swapped branch commitments are rejected,
+//! * `simulate`, and `extract` returning the witness of whichever branch was real.
+//!
+//!   cargo +nightly run --features backends --example sigma_or_combinator_ok
+
+use fsr_core::protocols::and::And;
+use fsr_core::protocols::or::{Either, Or};
+use fsr_core::protocols::schnorr::Schnorr;
+use fsr_core::sigma::{decode_exact, encode, prove_fischlin, prove_fs, verify_fischlin, verify_fs, Labels};
+use fsr_core::{FischlinParams, HashOracle, PrimeField, PrimeGroup, Result, SigmaProtocol};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+const FS_DST: &[u8] = b"YavOracle/SigmaOR/Combinator/FS";
+const FISCHLIN_DST: &[u8] = b"YavOracle/SigmaOR/Combinator/Fischlin";
+
+type Or2<G> = Or<Schnorr<G>, Schnorr<G>>;
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) -> Result<()> {
+    let sid = b"sigma-or-combinator-ok";
+    let (w0, w1) = (G::Scalar::random(rng), G::Scalar::random(rng));
+    let x = (G::generator() * w0, G::generator() * w1);
+    let unrelated = (x.0 + G::generator(), x.1);
+    let params = FischlinParams::new(16, 8);
+
+    // Either witness, both transforms.
+    for (side, w) in [("left", Either::Left(w0)), ("right", Either::Right(w1))] {
+        let fs = prove_fs::<Or2<G>, _, _>(HashOracle::new(FS_DST), &x, &w, sid, rng)?;
+        let fischlin = prove_fischlin::<Or2<G>, _, _>(HashOracle::new(FISCHLIN_DST), params, &x, &w, sid, rng)?;
+        assert!(verify_fs::<Or2<G>, _>(HashOracle::new(FS_DST), &x, sid, &fs), "{name}: FS proof ({side} witness) rejected");
+        assert!(
+            verify_fischlin::<Or2<G>, _>(HashOracle::new(FISCHLIN_DST), params, &x, sid, &fischlin),
+            "{name}: Fischlin proof ({side} witness) rejected"
+        );
+        assert!(
+            !verify_fs::<Or2<G>, _>(HashOracle::new(FS_DST), &unrelated, sid, &fs),
+            "{name}: FS proof ({side} witness) accepted for another statement"
+        );
+        assert!(
+            !verify_fischlin::<Or2<G>, _>(HashOracle::new(FISCHLIN_DST), params, &unrelated, sid, &fischlin),
+            "{name}: Fischlin proof ({side} witness) accepted for another statement"
+        );
+    }
+
+    // Tampering: shift the split (e0 + 1, so e1 − 1), or swap the branch commitments.
+    let fs = prove_fs::<Or2<G>, _, _>(HashOracle::new(FS_DST), &x, &Either::Right(w1), sid, rng)?;
+    let (e0, zs) = decode_exact::<(G::Scalar, (G::Scalar, G::Scalar))>(&fs.z[0]).unwrap();
+    let mut shifted = fs.clone();
+    shifted.z[0] = encode(&(e0 + G::Scalar::one(), zs));
+    let (a0, a1) = decode_exact::<(G, G)>(&fs.m[0]).unwrap();
+    let mut swapped = fs.clone();
+    swapped.m[0] = encode(&(a1, a0));
+    let shifted_ok = verify_fs::<Or2<G>, _>(HashOracle::new(FS_DST), &x, sid, &shifted);
+    let swapped_ok = verify_fs::<Or2<G>, _>(HashOracle::new(FS_DST), &x, sid, &swapped);
+
+    // y0 ∨ (y1 ∧ y2), proven with the AND branch.
+    type Mixed<G> = Or<Schnorr<G>, And<Schnorr<G>, Schnorr<G>>>;
+    let w2 = G::Scalar::random(rng);
+    let mixed_x = (x.0, (x.1, G::generator() * w2));
+    let mut labels = Labels::new();
+    Mixed::<G>::require(&mixed_x, &mut labels);
+    let mixed = prove_fs::<Mixed<G>, _, _>(HashOracle::new(FS_DST), &mixed_x, &Either::Right((w1, w2)), sid, rng)?;
+    let labels_ok = labels.as_slice() == ["or.0", "or.1/and.0", "or.1/and.1"];
+    let mixed_ok = verify_fs::<Mixed<G>, _>(HashOracle::new(FS_DST), &mixed_x, sid, &mixed);
+
+    // simulate / extract.
+    let e = G::Scalar::random(rng);
+    let (a_sim, z_sim) = Or2::<G>::simulate(&x, &e, rng);
+    let sim_ok = Or2::<G>::verify(&x, &a_sim, &e, &z_sim);
+    for (side, w) in [("left", Either::Left(w0)), ("right", Either::Right(w1))] {
+        let (a, st) = Or2::<G>::commit(&x, &w, rng);
+        let e2 = e + G::Scalar::one();
+        let transcripts = [(e, Or2::<G>::respond(&x, &w, &st, &e)), (e2, Or2::<G>::respond(&x, &w, &st, &e2))];
+        assert_eq!(Or2::<G>::extract(&x, &a, &transcripts), Some(w), "{name}: extract ({side} witness)");
+        assert!(
+            Or2::<G>::extract(&x, &a, &[transcripts[0], transcripts[0]]).is_none(),
+            "{name}: extract ({side} witness) accepted a repeated challenge"
+        );
+    }
+
+    println!(
+        "{name:<14} FS + Fischlin + extract with either witness ok, OR of AND = {mixed_ok}, \
+         tampered rejected = {}, simulate = {sim_ok}",
+        !shifted_ok && !swapped_ok
+    );
+    assert!(labels_ok, "{name}: OR of AND label paths are {:?}", labels.as_slice());
+    assert!(mixed_ok, "{name}: OR of AND proof rejected");
+    assert!(!shifted_ok, "{name}: shifted challenge split accepted");
+    assert!(!swapped_ok, "{name}: swapped branch commitments accepted");
+    assert!(sim_ok, "{name}: simulated transcript rejected");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(17);
+
+    for_each_backend!(G, name => run::<G>(name, &mut rng)?);
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! k-of-n partial knowledge with `protocols::threshold::Threshold`: "I know the discrete logs of
+//! k of these n keys", proven with the generic FS and Fischlin drivers on every backend.
+//! Checks
+//! * 3-of-5 with different known subsets (FS and Fischlin), and the k = 1 (OR) and k = n (AND)
+//!   extremes,
+//! * all n commitments are required labels (`thr.0` … `thr.4`),
+//! * rejection of a higher threshold, of shares off the polynomial, and of a missing branch,
+//! * `simulate`, and `extract` recovering k witnesses from two transcripts.
+//!
+//!   cargo +nightly run --features backends --example sigma_threshold
+
+use fsr_core::protocols::schnorr::Schnorr;
+use fsr_core::protocols::threshold::{Statement, Threshold};
+use fsr_core::sigma::{decode_exact, encode, prove_fischlin, prove_fs, verify_fischlin, verify_fs, Labels};
+use fsr_core::{FischlinParams, HashOracle, PrimeField, PrimeGroup, Result, SigmaProtocol};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[macro_use]
+mod backends;
+
+const FS_DST: &[u8] = b"YavOracle/Threshold/FS";
+const FISCHLIN_DST: &[u8] = b"YavOracle/Threshold/Fischlin";
+
+type Thr<G> = Threshold<Schnorr<G>>;
+
+fn run<G: PrimeGroup>(name: &str, rng: &mut StdRng) -> Result<()> {
+    let sid = b"sigma-threshold";
+    let keys: Vec<G::Scalar> = (0..5).map(|_| G::Scalar::random(rng)).collect();
+    let ys: Vec<G> = keys.iter().map(|w| G::generator() * *w).collect();
+    let witness = |known: &[usize]| known.iter().map(|&i| (i, keys[i])).collect::<Vec<_>>();
+    let params = FischlinParams::new(16, 8);
+
+    // k-of-5 for several k and known subsets.
+    for known in [&[0, 2, 4][..], &[1, 2, 3], &[3], &[0, 1, 2, 3, 4]] {
+        let x = Statement { k: known.len(), x: ys.clone() };
+        let fs = prove_fs::<Thr<G>, _, _>(HashOracle::new(FS_DST), &x, &witness(known), sid, rng)?;
+        let higher = Statement { k: known.len() + 1, ..x.clone() };
+        assert!(verify_fs::<Thr<G>, _>(HashOracle::new(FS_DST), &x, sid, &fs), "{name}: FS proof knowing {known:?} rejected");
+        assert!(
+            !verify_fs::<Thr<G>, _>(HashOracle::new(FS_DST), &higher, sid, &fs),
+            "{name}: FS proof knowing {known:?} accepted for k = {}",
+            higher.k
+        );
+    }
+
+    let x = Statement { k: 3, x: ys.clone() };
+    let fischlin = prove_fischlin::<Thr<G>, _, _>(HashOracle::new(FISCHLIN_DST), params, &x, &witness(&[0, 2, 4]), sid, rng)?;
+    let fischlin_ok = verify_fischlin::<Thr<G>, _>(HashOracle::new(FISCHLIN_DST), params, &x, sid, &fischlin);
+    let mut labels = Labels::new();
+    Thr::<G>::require(&x, &mut labels);
+    let labels_ok = labels.as_slice() == ["thr.0", "thr.1", "thr.2", "thr.3", "thr.4"];
+
+    // A share moved off the polynomial (with z still valid for it) and a dropped branch.
+    let fs = prove_fs::<Thr<G>, _, _>(HashOracle::new(FS_DST), &x, &witness(&[0, 1, 4]), sid, rng)?;
+    let a = decode_exact::<Vec<G>>(&fs.m[0]).unwrap();
+    let mut z = decode_exact::<Vec<(G::Scalar, G::Scalar)>>(&fs.z[0]).unwrap();
+    z[4] = (z[4].0 + G::Scalar::one(), z[4].1 + keys[4]);
+    let mut off_polynomial = fs.clone();
+    off_polynomial.z[0] = encode(&z);
+    let mut dropped = fs.clone();
+    dropped.m[0] = encode(&a[..4].to_vec());
+    let off_polynomial_ok = verify_fs::<Thr<G>, _>(HashOracle::new(FS_DST), &x, sid, &off_polynomial);
+    let dropped_ok = verify_fs::<Thr<G>, _>(HashOracle::new(FS_DST), &x, sid, &dropped);
+
+    // simulate / extract.
+    let e = G::Scalar::random(rng);
+    let (a_sim, z_sim) = Thr::<G>::simulate(&x, &e, rng);
+    let sim_ok = Thr::<G>::verify(&x, &a_sim, &e, &z_sim);
+    let w = witness(&[1, 3, 4]);
+    let (a, st) = Thr::<G>::commit(&x, &w, rng);
+    let e2 = e + G::Scalar::one();
+    let transcripts = [(e, Thr::<G>::respond(&x, &w, &st, &e)), (e2, Thr::<G>::respond(&x, &w, &st, &e2))];
+    let extract_ok = Thr::<G>::extract(&x, &a, &transcripts) == Some(w);
+    let extract_one = Thr::<G>::extract(&x, &a, &transcripts[..1]);
+
+    println!(
+        "{name:<14} FS (k = 3, 1, 5) ok, Fischlin (k = 3) = {fischlin_ok}, labels = {labels_ok}, tampered rejected = {}, \
+         simulate = {sim_ok}, extract = {extract_ok}, 3-of-5 FS proof = {} bytes",
+        !off_polynomial_ok && !dropped_ok,
+        fs.encode().len()
+    );
+    assert!(fischlin_ok, "{name}: Fischlin proof rejected");
+    assert!(labels_ok, "{name}: label paths are {:?}", labels.as_slice());
+    assert!(!off_polynomial_ok, "{name}: share off the polynomial accepted");
+    assert!(!dropped_ok, "{name}: proof with a branch dropped accepted");
+    assert!(sim_ok, "{name}: simulated transcript rejected");
+    assert!(extract_ok, "{name}: extract did not recover the k witnesses");
+    assert!(extract_one.is_none(), "{name}: extract accepted a single transcript");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(18);
+
+    for_each_backend!(G, name => run::<G>(name, &mut rng)?);
+
+    Ok(())
+}
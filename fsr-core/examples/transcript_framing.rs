@@ -0,0 +1,122 @@
+//! Transcript framing test vectors.
+//!
+//! Each case is a pair of absorb sequences that hashed to the same input under the old ad hoc
+//! encodings (`domain || label || bytes` in `HashOracle`, `|label|..|data|..` in `FSOracle` /
+//! `FischlinOracle`). With the shared `framing` encoding they now give different hash inputs,
+//! and therefore different challenges.
+//!
+//!   cargo +nightly run --example transcript_framing
+//!   cargo +nightly run --features sha3,sponge --example transcript_framing
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use fsr_core::framing::{self, op};
+use fsr_core::{
+    Absorb, FSOracle, FischlinOracle, FischlinParams, HashOracle, Oracle, RandomOracle, Result,
+    TranscriptRuntime, U64Challenge,
+};
+
+/// Records every `H_full` / `H` input so the hashed bytes can be compared directly.
+struct Recorder<RO> {
+    inner: RO,
+    inputs: Rc<RefCell<Vec<Vec<u8>>>>,
+}
+impl<RO: RandomOracle> RandomOracle for Recorder<RO> {
+    fn H_full(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+        self.inputs.borrow_mut().push(data.to_vec());
+        self.inner.H_full(label, data)
+    }
+    fn H(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+        self.inputs.borrow_mut().push(data.to_vec());
+        self.inner.H(label, data)
+    }
+}
+
+const DST: &[u8] = b"YavOracle/Framing";
+
+type Absorbs = &'static [(&'static str, &'static [u8])];
+
+fn old_hash_oracle(seq: Absorbs) -> Vec<u8> {
+    seq.iter().flat_map(|(l, d)| [DST, l.as_bytes(), d].concat()).collect()
+}
+fn old_fs(seq: Absorbs) -> Vec<u8> {
+    seq.iter().flat_map(|(l, d)| [b"|label|", l.as_bytes(), b"|data|", d].concat()).collect()
+}
+
+fn hash_oracle_challenge(seq: Absorbs) -> Result<u64> {
+    let mut h = HashOracle::new(DST);
+    for (l, d) in seq { h.absorb_bytes(l, d); }
+    Ok(h.challenge::<U64Challenge>("e")?.0)
+}
+
+fn fs_challenge<RO: RandomOracle>(ro: RO, seq: Absorbs) -> Vec<u8> {
+    let mut fs = FSOracle::new(ro);
+    for (l, d) in seq { fs.absorb(l, d); }
+    fs.derive_challenge("e", &[], 32)
+}
+
+fn fs_case<RO: RandomOracle>(name: &str, mk: impl Fn() -> RO, a: Absorbs, b: Absorbs) {
+    let differ = fs_challenge(mk(), a) != fs_challenge(mk(), b);
+    println!("  {name:<12} FS challenges differ = {differ}");
+    assert!(differ);
+}
+
+fn main() -> Result<()> {
+    // 1) Known-answer vector for one frame.
+    let frame = framing::framed(op::ABSORB, b"x", b"yz");
+    assert_eq!(hex::encode(&frame), "010100000000000000780200000000000000797a");
+    let mut rest = frame.as_slice();
+    assert_eq!(framing::parse_frame(&mut rest), Some((op::ABSORB, &b"x"[..], &b"yz"[..])));
+    assert!(rest.is_empty());
+    println!("frame(ABSORB, \"x\", \"yz\") = 0x{}", hex::encode(&frame));
+
+    // 2) HashOracle: the label/data boundary was not encoded.
+    const SPLIT_A: Absorbs = &[("ab", b"c")];
+    const SPLIT_B: Absorbs = &[("a", b"bc")];
+    assert_eq!(old_hash_oracle(SPLIT_A), old_hash_oracle(SPLIT_B));
+    let differ = hash_oracle_challenge(SPLIT_A)? != hash_oracle_challenge(SPLIT_B)?;
+    println!("HashOracle [(ab, c)] vs [(a, bc)]: old inputs equal, challenges differ = {differ}");
+    assert!(differ);
+
+    // 3) FSOracle: data containing the separators forged a second absorb.
+    const FORGED: Absorbs = &[("m", b"1|label|m|data|2")];
+    const HONEST: Absorbs = &[("m", b"1"), ("m", b"2")];
+    assert_eq!(old_fs(FORGED), old_fs(HONEST));
+    println!("FSOracle [(m, 1|label|m|data|2)] vs [(m, 1), (m, 2)]: old inputs equal");
+    fs_case("HashOracle", || HashOracle::new(DST), FORGED, HONEST);
+    #[cfg(feature = "sha3")]
+    {
+        fs_case("SHA3-256", || fsr_core::Sha3Oracle::new(DST), FORGED, HONEST);
+        // Known answer, with F(op, label, data) = SHA3-256(frame(DOMAIN, "domain", DST) || frame(op, label, data) || 0u32):
+        //   chain = F(H_FULL, "fs.chain", frame(DOMAIN, "chain", "") || frame(ABSORB, "m", "1") || frame(ABSORB, "m", "2"))
+        //   e     = F(H_XOF, "e", 32u64 || frame(DOMAIN, "mode", "FS") || frame(ABSORB, "chain", chain) || frame(EXTRA, "extra", ""))
+        let e = fs_challenge(fsr_core::Sha3Oracle::new(DST), HONEST);
+        assert_eq!(hex::encode(e), "bbad7d91b2ea7d7495ce78df826fc3def106628a5670dd5c407bb3366437bd9b");
+    }
+    #[cfg(feature = "sponge")]
+    fs_case("sponge", || fsr_core::SpongeOracle::new(DST), FORGED, HONEST);
+
+    // 4) Fischlin predicate: `|e=..|z=..` let bytes move between e and z.
+    let (e1, z1): (&[u8], &[u8]) = (b"1|z=2", b"3");
+    let (e2, z2): (&[u8], &[u8]) = (b"1", b"2|z=3");
+    let old = |e: &[u8], z: &[u8]| [&b"|e="[..], e, b"|z=", z].concat();
+    assert_eq!(old(e1, z1), old(e2, z2));
+
+    let inputs = Rc::new(RefCell::new(Vec::new()));
+    let params = FischlinParams::new(16, 8);
+    let mut f = FischlinOracle::new(Recorder { inner: HashOracle::new(DST), inputs: inputs.clone() }, params);
+    f.begin(b"x", b"sid");
+    for i in 0..params.rho { f.push_first_message(&[i as u8])?; }
+    f.seal_first_messages()?;
+    let prefix = f.predicate_prefix(0)?;
+    inputs.borrow_mut().clear();
+    f.hb_zero_from_prefix(&prefix, e1, z1);
+    f.hb_zero_from_prefix(&prefix, e2, z2);
+    let h_b = inputs.borrow();
+    let differ = h_b[0] != h_b[1];
+    println!("Fischlin H_b (e=1|z=2, z=3) vs (e=1, z=2|z=3): old inputs equal, new inputs differ = {differ}");
+    assert!(differ);
+
+    Ok(())
+}
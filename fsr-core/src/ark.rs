@@ -0,0 +1,120 @@
+//! arkworks interop (feature = "ark").
+//!
+//! Transcript encodings come from ark-serialize in compressed mode, and decoding runs full
+//! validation (on the curve, in the prime-order subgroup, field elements canonical), so the
+//! `CanonicalEncode` / `CanonicalDecode` contract holds for every type covered here:
+//!
+//! * prime fields `Fp<P, N>` (every arkworks `PrimeField`: scalar and base fields of all curves),
+//!   which are also `Challenge`s (|p| + 128 oracle bits reduced mod p) and `PrimeField`s;
+//! * short-Weierstrass and twisted-Edwards `Affine<P>` / `Projective<P>` points (the `ark_ec`
+//!   affine types are what `FsrBindable` message fields usually hold), whose `Projective` forms
+//!   are `PrimeGroup`s over their scalar field;
+//! * pairing targets `PairingOutput<E>`, also `PrimeGroup`s;
+//! * anything else `CanonicalSerialize + CanonicalDeserialize` (extension fields, structs of
+//!   ark values) through the `Ark<T>` wrapper.
+//!
+//! A single `impl<T: CanonicalSerialize> CanonicalEncode for T` would conflict with the existing
+//! impls for integers and byte arrays (ark-serialize implements its traits for those too), which
+//! is why the impls are per arkworks type family.
+
+use ark_ec::pairing::{Pairing, PairingOutput};
+use ark_ec::{short_weierstrass as sw, twisted_edwards as te, CurveGroup, Group, VariableBaseMSM};
+use ark_ff::{Field, Fp, FpConfig, One, PrimeField as ArkPrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+use crate::challenge::WIDE_EXTRA_BITS;
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::{CanonicalDecode, CanonicalEncode, Challenge};
+
+/// Compressed ark-serialize encoding of `v`.
+pub fn ark_encode<T: CanonicalSerialize>(v: &T, out: &mut Vec<u8>) {
+    v.serialize_with_mode(out, Compress::Yes).expect("writing to a Vec cannot fail");
+}
+
+/// Compressed, validated ark-serialize decoding; `input` only advances on success.
+pub fn ark_decode<T: CanonicalDeserialize>(input: &mut &[u8]) -> Option<T> {
+    let mut peek = *input;
+    let v = T::deserialize_with_mode(&mut peek, Compress::Yes, Validate::Yes).ok()?;
+    *input = peek;
+    Some(v)
+}
+
+/// Any arkworks-serializable value, encoded compressed and decoded with validation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Ark<T>(pub T);
+
+macro_rules! ark_codec {
+    ($(impl[$($g:tt)*] $ty:ty;)*) => {$(
+        impl<$($g)*> CanonicalEncode for $ty {
+            fn encode(&self, out: &mut Vec<u8>) { ark_encode(self, out) }
+        }
+        impl<$($g)*> CanonicalDecode for $ty {
+            fn decode(input: &mut &[u8]) -> Option<Self> { ark_decode(input) }
+        }
+    )*};
+}
+
+ark_codec! {
+    impl[P: FpConfig<N>, const N: usize] Fp<P, N>;
+    impl[P: sw::SWCurveConfig] sw::Affine<P>;
+    impl[P: sw::SWCurveConfig] sw::Projective<P>;
+    impl[P: te::TECurveConfig] te::Affine<P>;
+    impl[P: te::TECurveConfig] te::Projective<P>;
+    impl[E: Pairing] PairingOutput<E>;
+}
+
+impl<T: CanonicalSerialize> CanonicalEncode for Ark<T> {
+    fn encode(&self, out: &mut Vec<u8>) { ark_encode(&self.0, out) }
+}
+impl<T: CanonicalDeserialize> CanonicalDecode for Ark<T> {
+    fn decode(input: &mut &[u8]) -> Option<Self> { ark_decode(input).map(Ark) }
+}
+
+impl<P: FpConfig<N>, const N: usize> Challenge for Fp<P, N> {
+    const BYTES: usize = (Self::MODULUS_BIT_SIZE as usize).div_ceil(8) + WIDE_EXTRA_BITS / 8;
+    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self {
+        Self::from_le_bytes_mod_order(input)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> PrimeField for Fp<P, N> {
+    fn zero() -> Self { Zero::zero() }
+    fn one() -> Self { One::one() }
+    fn from_u64(v: u64) -> Self { Self::from(v) }
+    fn invert(&self) -> Option<Self> { self.inverse() }
+}
+
+impl<P: sw::SWCurveConfig> PrimeGroup for sw::Projective<P>
+where
+    P::ScalarField: PrimeField,
+{
+    type Scalar = P::ScalarField;
+    fn generator() -> Self { <Self as Group>::generator() }
+    fn identity() -> Self { Zero::zero() }
+    fn msm(terms: &[(Self::Scalar, Self)]) -> Self { curve_msm(terms) }
+}
+
+impl<P: te::TECurveConfig> PrimeGroup for te::Projective<P>
+where
+    P::ScalarField: PrimeField,
+{
+    type Scalar = P::ScalarField;
+    fn generator() -> Self { <Self as Group>::generator() }
+    fn identity() -> Self { Zero::zero() }
+    fn msm(terms: &[(Self::Scalar, Self)]) -> Self { curve_msm(terms) }
+}
+
+impl<E: Pairing> PrimeGroup for PairingOutput<E>
+where
+    E::ScalarField: PrimeField,
+{
+    type Scalar = E::ScalarField;
+    fn generator() -> Self { <Self as Group>::generator() }
+    fn identity() -> Self { Zero::zero() }
+}
+
+/// Pippenger over the batch-normalized points (arkworks' `VariableBaseMSM` takes affine bases).
+fn curve_msm<C: CurveGroup + VariableBaseMSM<MulBase = C::Affine>>(terms: &[(C::ScalarField, C)]) -> C {
+    let (scalars, points): (Vec<_>, Vec<_>) = terms.iter().copied().unzip();
+    C::msm(&C::normalize_batch(&points), &scalars).expect("one scalar per point")
+}
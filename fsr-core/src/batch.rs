@@ -0,0 +1,123 @@
+//! Batch verification of many FS proofs for linear sigma protocols.
+//!
+//! A `LinearSigma` verifier accepts iff a few group equations Σ s_k·P_k = 0 hold, with the s_k
+//! computed from (e, z). `verify_fs_batch` replays every proof's transcript (the same one as
+//! `sigma::verify_fs`), draws an independent 128-bit weight d_j per equation from the verifier's
+//! rng, and checks Σ_j d_j·(equation j) = 0 with one `PrimeGroup::msm`; the generator terms of
+//! all equations are merged into a single one. If any equation fails, the combination is zero
+//! with probability at most 2^-128 over the weights.
+//!
+//! A failing batch is bisected: each half is checked again with fresh weights, down to single
+//! proofs, so k bad proofs among n cost O(k·log n) extra checks. Proofs that do not decode or
+//! bind are reported without entering the combination.
+
+use rand_core::RngCore;
+
+use crate::fs_proof::FsProof;
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::runtime::RandomOracle;
+use crate::sigma::{fs_transcripts, SigmaProtocol};
+
+/// base·G + Σ s_k·P_k = 0, G the group's generator (kept apart so a batch adds one G term).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Equation<G: PrimeGroup> {
+    pub base: G::Scalar,
+    pub terms: Vec<(G::Scalar, G)>,
+}
+
+impl<G: PrimeGroup> Equation<G> {
+    /// Whether the equation holds on its own (one `msm`).
+    pub fn holds(&self) -> bool {
+        let mut terms = self.terms.clone();
+        terms.push((self.base, G::generator()));
+        G::msm(&terms).is_identity()
+    }
+}
+
+/// A sigma protocol whose `verify` is exactly a list of group equations over `Group`.
+pub trait LinearSigma: SigmaProtocol<Challenge = <Self::Group as PrimeGroup>::Scalar> {
+    type Group: PrimeGroup;
+
+    /// The equations `verify(x, a, e, z)` checks, appended to `out`; `false` if the transcript
+    /// is rejected before any group check (shapes that do not match, a failed scalar check).
+    fn equations(
+        x: &Self::Statement,
+        a: &Self::Commitment,
+        e: &Self::Challenge,
+        z: &Self::Response,
+        out: &mut Vec<Equation<Self::Group>>,
+    ) -> bool;
+}
+
+/// Verify FS proofs `(x, sid, proof)` of `P`, each under `FSOracle::new(ro.clone())`, with one
+/// multi-scalar multiplication. `Err` lists the indices of the proofs that fail, ascending;
+/// `rng` must be the verifier's own (the weights have to be unpredictable to provers).
+pub fn verify_fs_batch<P: LinearSigma, RO: RandomOracle + Clone, R: RngCore + ?Sized>(
+    ro: &RO,
+    proofs: &[(&P::Statement, &[u8], &FsProof)],
+    rng: &mut R,
+) -> core::result::Result<(), Vec<usize>> {
+    let mut failed = Vec::new();
+    let mut batch = Vec::with_capacity(proofs.len());
+    for (i, (x, sid, proof)) in proofs.iter().enumerate() {
+        match fs_equations::<P, RO>(ro.clone(), x, sid, proof) {
+            Some(eqs) => batch.push((i, eqs)),
+            None => failed.push(i),
+        }
+    }
+    bisect(&batch, false, rng, &mut failed);
+    failed.sort_unstable();
+    if failed.is_empty() { Ok(()) } else { Err(failed) }
+}
+
+/// The equations of every repetition of `proof`; `None` if it is rejected outright.
+fn fs_equations<P: LinearSigma, RO: RandomOracle>(
+    ro: RO,
+    x: &P::Statement,
+    sid: &[u8],
+    proof: &FsProof,
+) -> Option<Vec<Equation<P::Group>>> {
+    let mut eqs = Vec::new();
+    for (a, e, z) in fs_transcripts::<P, RO>(ro, x, sid, proof)? {
+        if !P::equations(x, &a, &e, &z, &mut eqs) { return None; }
+    }
+    Some(eqs)
+}
+
+/// Push the indices of the failing entries of `batch`; `known_bad` skips the check of a batch
+/// whose failure already follows from its parent's and sibling's.
+fn bisect<G: PrimeGroup, R: RngCore + ?Sized>(
+    batch: &[(usize, Vec<Equation<G>>)],
+    known_bad: bool,
+    rng: &mut R,
+    failed: &mut Vec<usize>,
+) {
+    if batch.is_empty() || (!known_bad && holds(batch, rng)) { return; }
+    if let [(i, _)] = batch {
+        failed.push(*i);
+        return;
+    }
+    let (left, right) = batch.split_at(batch.len() / 2);
+    let left_ok = holds(left, rng);
+    if !left_ok { bisect(left, true, rng, failed); }
+    bisect(right, left_ok, rng, failed);
+}
+
+/// Σ_j d_j·(equation j) = 0 for fresh random weights d_j.
+fn holds<G: PrimeGroup, R: RngCore + ?Sized>(batch: &[(usize, Vec<Equation<G>>)], rng: &mut R) -> bool {
+    let mut base = G::Scalar::zero();
+    let mut terms = Vec::with_capacity(batch.iter().flat_map(|(_, eqs)| eqs).map(|eq| eq.terms.len()).sum::<usize>() + 1);
+    for eq in batch.iter().flat_map(|(_, eqs)| eqs) {
+        let d = weight::<G::Scalar, R>(rng);
+        base = base + d * eq.base;
+        terms.extend(eq.terms.iter().map(|(s, p)| (d * *s, *p)));
+    }
+    terms.push((base, G::generator()));
+    G::msm(&terms).is_identity()
+}
+
+/// A uniform 128-bit integer as a field element.
+fn weight<F: PrimeField, R: RngCore + ?Sized>(rng: &mut R) -> F {
+    let two_32 = F::from_u64(1 << 32);
+    F::from_u64(rng.next_u64()) * two_32 * two_32 + F::from_u64(rng.next_u64())
+}
@@ -0,0 +1,311 @@
+//! Challenge reduction strategies.
+//!
+//! Each strategy is a `Challenge` type whose `BYTES` tells the oracle how much output to produce:
+//!
+//! * `WideReduction<M>` — read `|M| + 128` bits and reduce mod `M`; statistical distance from
+//!   uniform is below 2^-128. The default choice for scalar-field challenges.
+//! * `RejectionSampled<M, RETRIES>` — read `RETRIES` candidates of `|M|` bits and take the first
+//!   one below `M`. Exactly uniform unless every candidate is rejected (probability < 2^-RETRIES),
+//!   in which case the last candidate is reduced mod `M`.
+//! * `TruncatedBits<B>` — the low `B` bits (B ≤ 64), for binary and other small power-of-two
+//!   challenge spaces (e.g. Fischlin's per-repetition challenges).
+//!
+//! Oracle bytes are read as a little-endian integer. Moduli up to 256 bits are supported;
+//! `Mod64<Q>` covers single-word toy moduli and the common curve orders are predefined.
+//! A protocol scalar type can delegate to a strategy:
+//!
+//! ```ignore
+//! impl Challenge for Scalar {
+//!     const BYTES: usize = WideReduction::<Ed25519Order>::BYTES;
+//!     fn from_oracle_bytes(label: &str, b: &[u8]) -> Self {
+//!         Scalar::from_limbs(WideReduction::<Ed25519Order>::from_oracle_bytes(label, b).0.limbs())
+//!     }
+//! }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{CanonicalDecode, CanonicalEncode, Challenge};
+
+/// Extra bits read beyond the modulus size for wide reduction.
+pub const WIDE_EXTRA_BITS: usize = 128;
+
+/// A challenge-space modulus `m` (2 ≤ m < 2^256), as little-endian 64-bit limbs.
+pub trait Modulus: 'static {
+    const LIMBS: [u64; 4];
+    const BITS: u32 = bit_len(&Self::LIMBS);
+    const BYTE_LEN: usize = (Self::BITS as usize).div_ceil(8);
+}
+
+const fn bit_len(l: &[u64; 4]) -> u32 {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if l[i] != 0 {
+            return 64 * i as u32 + (64 - l[i].leading_zeros());
+        }
+    }
+    0
+}
+
+/// Single-word modulus, e.g. the toy group order in the examples.
+pub struct Mod64<const Q: u64>;
+impl<const Q: u64> Modulus for Mod64<Q> {
+    const LIMBS: [u64; 4] = [Q, 0, 0, 0];
+}
+
+/// Order of the Ed25519 / Ristretto255 prime-order group.
+pub struct Ed25519Order;
+impl Modulus for Ed25519Order {
+    const LIMBS: [u64; 4] = [0x5812631a5cf5d3ed, 0x14def9dea2f79cd6, 0, 0x1000000000000000];
+}
+
+/// Order of the secp256k1 group.
+pub struct Secp256k1Order;
+impl Modulus for Secp256k1Order {
+    const LIMBS: [u64; 4] = [0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff];
+}
+
+/// Order of the NIST P-256 group.
+pub struct P256Order;
+impl Modulus for P256Order {
+    const LIMBS: [u64; 4] = [0xf3b9cac2fc632551, 0xbce6faada7179e84, 0xffffffffffffffff, 0xffffffff00000000];
+}
+
+/// BLS12-381 scalar field modulus r.
+pub struct Bls12381Order;
+impl Modulus for Bls12381Order {
+    const LIMBS: [u64; 4] = [0xffffffff00000001, 0x53bda402fffe5bfe, 0x3339d80809a1d805, 0x73eda753299d7d48];
+}
+
+/// BN254 scalar field modulus r.
+pub struct Bn254Order;
+impl Modulus for Bn254Order {
+    const LIMBS: [u64; 4] = [0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029];
+}
+
+// ---------------- 256-bit helpers ----------------
+
+fn geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+fn sub_assign(a: &mut [u64; 4], b: &[u64; 4]) {
+    let mut borrow = false;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        a[i] = d;
+        borrow = b1 || b2;
+    }
+}
+
+/// `bytes` (little-endian, any length) mod `m`.
+fn reduce_le(bytes: &[u8], m: &[u64; 4]) -> [u64; 4] {
+    let mut acc = [0u64; 4];
+    for &byte in bytes.iter().rev() {
+        for bit in (0..8).rev() {
+            // acc = 2*acc + bit, with the carry out of 2^256 kept in `top`.
+            let top = acc[3] >> 63 == 1;
+            for i in (1..4).rev() {
+                acc[i] = (acc[i] << 1) | (acc[i - 1] >> 63);
+            }
+            acc[0] = (acc[0] << 1) | ((byte >> bit) & 1) as u64;
+            if top || geq(&acc, m) {
+                sub_assign(&mut acc, m);
+            }
+        }
+    }
+    acc
+}
+
+/// `bytes` (little-endian, at most 32) as limbs, keeping only the low `bits` bits.
+fn masked_le(bytes: &[u8], bits: u32) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    for (i, &b) in bytes.iter().enumerate().take(32) {
+        out[i / 8] |= (b as u64) << (8 * (i % 8));
+    }
+    for (i, limb) in out.iter_mut().enumerate() {
+        let lo = 64 * i as u32;
+        if bits <= lo {
+            *limb = 0;
+        } else if bits < lo + 64 {
+            *limb &= (1u64 << (bits - lo)) - 1;
+        }
+    }
+    out
+}
+
+// ---------------- Values ----------------
+
+/// An integer in `[0, M)`.
+pub struct ScalarMod<M: Modulus> {
+    limbs: [u64; 4],
+    _m: PhantomData<M>,
+}
+
+impl<M: Modulus> ScalarMod<M> {
+    /// `None` unless `limbs < M`.
+    pub fn from_limbs(limbs: [u64; 4]) -> Option<Self> {
+        if geq(&limbs, &M::LIMBS) { None } else { Some(Self { limbs, _m: PhantomData }) }
+    }
+
+    /// Any-length little-endian bytes, reduced mod `M`.
+    pub fn from_le_bytes_mod(bytes: &[u8]) -> Self {
+        Self { limbs: reduce_le(bytes, &M::LIMBS), _m: PhantomData }
+    }
+
+    pub fn limbs(&self) -> [u64; 4] { self.limbs }
+
+    /// Low 64 bits (the whole value for `Mod64` moduli).
+    pub fn as_u64(&self) -> u64 { self.limbs[0] }
+
+    /// `M::BYTE_LEN` little-endian bytes.
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(M::BYTE_LEN);
+        for i in 0..M::BYTE_LEN {
+            out.push((self.limbs[i / 8] >> (8 * (i % 8))) as u8);
+        }
+        out
+    }
+}
+
+impl<M: Modulus> Clone for ScalarMod<M> {
+    fn clone(&self) -> Self { *self }
+}
+impl<M: Modulus> Copy for ScalarMod<M> {}
+impl<M: Modulus> PartialEq for ScalarMod<M> {
+    fn eq(&self, other: &Self) -> bool { self.limbs == other.limbs }
+}
+impl<M: Modulus> Eq for ScalarMod<M> {}
+impl<M: Modulus> fmt::Debug for ScalarMod<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ScalarMod(0x")?;
+        for l in self.limbs.iter().rev() {
+            write!(f, "{l:016x}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<M: Modulus> CanonicalEncode for ScalarMod<M> {
+    fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.to_le_bytes()); }
+}
+
+/// Strict: exactly `M::BYTE_LEN` bytes encoding a value below `M`.
+impl<M: Modulus> CanonicalDecode for ScalarMod<M> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        if input.len() < M::BYTE_LEN { return None; }
+        let (head, rest) = input.split_at(M::BYTE_LEN);
+        let v = Self::from_limbs(masked_le(head, 8 * M::BYTE_LEN as u32))?;
+        *input = rest;
+        Some(v)
+    }
+}
+
+// ---------------- Strategies ----------------
+
+// Value-like traits without requiring them of the modulus marker type.
+macro_rules! value_traits {
+    (impl[$($g:tt)*] $ty:ty) => {
+        impl<$($g)*> Clone for $ty { fn clone(&self) -> Self { *self } }
+        impl<$($g)*> Copy for $ty {}
+        impl<$($g)*> PartialEq for $ty { fn eq(&self, o: &Self) -> bool { self.0 == o.0 } }
+        impl<$($g)*> Eq for $ty {}
+        impl<$($g)*> fmt::Debug for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.0.fmt(f) }
+        }
+    };
+}
+
+/// Wide modular reduction: `|M| + 128` oracle bits reduced mod `M`.
+pub struct WideReduction<M: Modulus>(pub ScalarMod<M>);
+
+impl<M: Modulus> Challenge for WideReduction<M> {
+    const BYTES: usize = M::BYTE_LEN + WIDE_EXTRA_BITS / 8;
+    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self {
+        WideReduction(ScalarMod::from_le_bytes_mod(input))
+    }
+}
+
+impl<M: Modulus> CanonicalEncode for WideReduction<M> {
+    fn encode(&self, out: &mut Vec<u8>) { self.0.encode(out); }
+}
+impl<M: Modulus> CanonicalDecode for WideReduction<M> {
+    fn decode(input: &mut &[u8]) -> Option<Self> { ScalarMod::decode(input).map(WideReduction) }
+}
+
+value_traits!(impl[M: Modulus] WideReduction<M>);
+
+/// Rejection sampling with up to `RETRIES` candidates of `|M|` bits each.
+pub struct RejectionSampled<M: Modulus, const RETRIES: usize = 16>(pub ScalarMod<M>);
+value_traits!(impl[M: Modulus, const RETRIES: usize] RejectionSampled<M, RETRIES>);
+
+impl<M: Modulus, const RETRIES: usize> RejectionSampled<M, RETRIES> {
+    /// The sampled value and the index of the accepted candidate
+    /// (`None` if all `RETRIES` were rejected and the last one was reduced instead).
+    pub fn sample(input: &[u8]) -> (ScalarMod<M>, Option<usize>) {
+        let mut last: &[u8] = &[];
+        for (attempt, chunk) in input.chunks(M::BYTE_LEN).take(RETRIES).enumerate() {
+            if chunk.len() == M::BYTE_LEN
+                && let Some(v) = ScalarMod::from_limbs(masked_le(chunk, M::BITS))
+            {
+                return (v, Some(attempt));
+            }
+            last = chunk;
+        }
+        (ScalarMod::from_le_bytes_mod(last), None)
+    }
+}
+
+impl<M: Modulus, const RETRIES: usize> Challenge for RejectionSampled<M, RETRIES> {
+    const BYTES: usize = RETRIES * M::BYTE_LEN;
+    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self {
+        RejectionSampled(Self::sample(input).0)
+    }
+}
+
+impl<M: Modulus, const RETRIES: usize> CanonicalEncode for RejectionSampled<M, RETRIES> {
+    fn encode(&self, out: &mut Vec<u8>) { self.0.encode(out); }
+}
+impl<M: Modulus, const RETRIES: usize> CanonicalDecode for RejectionSampled<M, RETRIES> {
+    fn decode(input: &mut &[u8]) -> Option<Self> { ScalarMod::decode(input).map(RejectionSampled) }
+}
+
+/// The low `B` bits of the oracle output (1 ≤ B ≤ 64); the modulus is 2^B.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TruncatedBits<const B: u32>(pub u64);
+
+impl<const B: u32> TruncatedBits<B> {
+    const VALID: () = assert!(B >= 1 && B <= 64, "TruncatedBits<B> needs 1 <= B <= 64");
+    const MASK: u64 = if B == 64 { u64::MAX } else { (1u64 << B) - 1 };
+}
+
+impl<const B: u32> Challenge for TruncatedBits<B> {
+    const BYTES: usize = (B as usize).div_ceil(8);
+    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self {
+        let () = Self::VALID;
+        TruncatedBits(masked_le(&input[..input.len().min(8)], B)[0] & Self::MASK)
+    }
+}
+
+impl<const B: u32> CanonicalEncode for TruncatedBits<B> {
+    fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.0.to_le_bytes()[..Self::BYTES]); }
+}
+impl<const B: u32> CanonicalDecode for TruncatedBits<B> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        if input.len() < Self::BYTES { return None; }
+        let mut b = [0u8; 8];
+        b[..Self::BYTES].copy_from_slice(&input[..Self::BYTES]);
+        let v = u64::from_le_bytes(b);
+        if v & !Self::MASK != 0 { return None; }
+        *input = &input[Self::BYTES..];
+        Some(TruncatedBits(v))
+    }
+}
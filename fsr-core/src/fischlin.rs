@@ -1,6 +1,7 @@
 //! Fischlin's transform runtime (prover & predicate helper) — optimized
 
 use core::marker::PhantomData;
+use crate::framing::{self, op};
 use crate::runtime::{TranscriptRuntime, RandomOracle, trunc_b_to_u64};
 use crate::{Result, ProveError, TranscriptOracle};
 
@@ -36,12 +37,11 @@ pub struct FischlinOracle<RO: RandomOracle> {
     // coverage/DS transcript (mode-agnostic)
     transcript_buf: Vec<u8>,
 
-    // bound inputs for common_h
-    statement_bytes: Vec<u8>,
-    sid_bytes: Vec<u8>,
-    m_vec: Vec<Vec<u8>>,
+    // common_h input, framed incrementally: mode, x, sid, then one frame per m_i
+    common_buf: Vec<u8>,
+    m_count: usize,
 
-    // common hash H_full(mode|x|m⃗|sid)
+    // common hash H_full(mode|x|sid|m⃗)
     common_h: Option<Vec<u8>>,
 
     // reusable scratch buffer to minimize per-try allocations
@@ -55,8 +55,7 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
         Self {
             params, ro, phase: Phase::Init,
             transcript_buf: Vec::new(),
-            statement_bytes: Vec::new(), sid_bytes: Vec::new(),
-            m_vec: Vec::new(), common_h: None,
+            common_buf: Vec::new(), m_count: 0, common_h: None,
             scratch: Vec::new(),
             _pd: PhantomData,
         }
@@ -64,12 +63,7 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
 
     pub fn begin(&mut self, statement: &[u8], sid: &[u8]) {
         self.phase = Phase::CollectingFirstMsgs;
-        self.statement_bytes.clear();
-        self.statement_bytes.extend_from_slice(statement);
-        self.sid_bytes.clear();
-        self.sid_bytes.extend_from_slice(sid);
-        self.m_vec.clear();
-        self.common_h = None;
+        self.start_common(statement, sid);
         self.scratch.clear();
 
         self.absorb("mode", b"FISCHLIN");
@@ -81,7 +75,7 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
         if !matches!(self.phase, Phase::CollectingFirstMsgs) {
             return Err(ProveError::Malformed("fischlin: push_first_message before begin"));
         }
-        self.m_vec.push(m_i.to_vec());
+        self.push_common(m_i);
         self.absorb("m_i", m_i);
         Ok(())
     }
@@ -90,7 +84,7 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
         if !matches!(self.phase, Phase::CollectingFirstMsgs) {
             return Err(ProveError::Malformed("fischlin: seal called before begin"));
         }
-        if self.m_vec.len() as u16 != self.params.rho {
+        if self.m_count != self.params.rho as usize {
             return Err(ProveError::Malformed("fischlin: m_vec len != rho"));
         }
         // n-special soundness parameter check: rho * (b - ceil_log2(n-1)) >= kappa_c
@@ -104,53 +98,49 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
             return Err(ProveError::UnsoundParams("fischlin: rho*(b - log2(n-1)) < kappa_c"));
         }
 
-        let mut buf = Vec::new();
-        buf.extend_from_slice(b"mode:FISCHLIN|x|"); buf.extend_from_slice(&self.statement_bytes);
-        buf.extend_from_slice(b"|m_vec|");
-        for (i, m) in self.m_vec.iter().enumerate() {
-            buf.extend_from_slice(b"i="); buf.extend_from_slice(&(i as u32).to_le_bytes());
-            buf.extend_from_slice(b":m="); buf.extend_from_slice(m); buf.push(0xff);
-        }
-        buf.extend_from_slice(b"|sid|"); buf.extend_from_slice(&self.sid_bytes);
-
-        let ch = self.ro.H_full("fischlin.common", &buf);
+        let ch = self.ro.H_full("fischlin.common", &self.common_buf);
         self.common_h = Some(ch);
         self.phase = Phase::Sealed;
         Ok(())
     }
 
-    /// NEW: Build the per-repetition predicate prefix once: "pred|common_h|i=<i>"
+    /// Reset the `common_h` input to frames for the mode, x and sid; `push_common` appends m_i.
+    fn start_common(&mut self, statement: &[u8], sid: &[u8]) {
+        self.common_buf.clear();
+        framing::frame(&mut self.common_buf, op::DOMAIN, b"mode", b"FISCHLIN");
+        framing::frame(&mut self.common_buf, op::ABSORB, b"x", statement);
+        framing::frame(&mut self.common_buf, op::ABSORB, b"sid", sid);
+        self.m_count = 0;
+        self.common_h = None;
+    }
+
+    fn push_common(&mut self, m_i: &[u8]) {
+        framing::frame(&mut self.common_buf, op::ABSORB, b"m_i", m_i);
+        self.m_count += 1;
+    }
+
+    /// NEW: Build the per-repetition predicate prefix once: frame(common_h) || frame(i)
     pub fn predicate_prefix(&self, i: u32) -> Result<Vec<u8>> {
         let common = self
             .common_h
             .as_ref()
-            .ok_or(ProveError::Malformed("fischlin: common_h missing"))?
-            .clone();
-        let mut prefix = Vec::with_capacity(common.len() + 16);
-        prefix.extend_from_slice(b"pred|");
-        prefix.extend_from_slice(&common);
-        prefix.extend_from_slice(b"|i=");
-        prefix.extend_from_slice(&i.to_le_bytes());
-        Ok(prefix)
+            .ok_or(ProveError::Malformed("fischlin: common_h missing"))?;
+        Ok(pred_prefix(common, i))
     }
 
     /// NEW: Predicate check using a precomputed prefix; only (e,z) vary.
     pub fn hb_zero_from_prefix(&mut self, prefix: &[u8], e: &[u8], z: &[u8]) -> bool {
         self.scratch.clear();
         self.scratch.extend_from_slice(prefix);
-        self.scratch.extend_from_slice(b"|e="); self.scratch.extend_from_slice(e);
-        self.scratch.extend_from_slice(b"|z="); self.scratch.extend_from_slice(z);
+        framing::frame(&mut self.scratch, op::ABSORB, b"e", e);
+        framing::frame(&mut self.scratch, op::ABSORB, b"z", z);
         let hb = self.ro.H("fischlin.H_b", &self.scratch);
         trunc_b_to_u64(&hb, self.params.b) == 0
     }
 
     /// Backwards-compatible predicate (now implemented via prefix)
     fn hb_predicate_zero(&mut self, common_h: &[u8], i: u32, e: &[u8], z: &[u8]) -> bool {
-        let mut prefix = Vec::with_capacity(common_h.len() + 16);
-        prefix.extend_from_slice(b"pred|");
-        prefix.extend_from_slice(common_h);
-        prefix.extend_from_slice(b"|i=");
-        prefix.extend_from_slice(&i.to_le_bytes());
+        let prefix = pred_prefix(common_h, i);
         self.hb_zero_from_prefix(&prefix, e, z)
     }
 
@@ -171,11 +161,7 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
         let bound = 1u64.checked_shl(t as u32).unwrap_or(0);
 
         // Precompute prefix once
-        let mut prefix = Vec::with_capacity(common.len() + 16);
-        prefix.extend_from_slice(b"pred|");
-        prefix.extend_from_slice(&common);
-        prefix.extend_from_slice(b"|i=");
-        prefix.extend_from_slice(&i.to_le_bytes());
+        let prefix = pred_prefix(&common, i);
 
         // Reusable e buffer
         let mut e_bytes = vec![0u8; ((t as usize + 7) / 8).max(1)];
@@ -210,11 +196,7 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
         let bound = 1u64.checked_shl(t as u32).unwrap_or(0);
 
         // Precompute prefix once
-        let mut prefix = Vec::with_capacity(common.len() + 16);
-        prefix.extend_from_slice(b"pred|");
-        prefix.extend_from_slice(&common);
-        prefix.extend_from_slice(b"|i=");
-        prefix.extend_from_slice(&i.to_le_bytes());
+        let prefix = pred_prefix(&common, i);
 
         // Reusable e buffer
         let mut e_bytes = vec![0u8; ((t as usize + 7) / 8).max(1)];
@@ -243,33 +225,18 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
             Some(h) => h.clone(),
             None => return false,
         };
-        let mut prefix = Vec::with_capacity(common.len() + 16);
-        prefix.extend_from_slice(b"pred|");
-        prefix.extend_from_slice(&common);
-        prefix.extend_from_slice(b"|i=");
-        prefix.extend_from_slice(&(i as u32).to_le_bytes());
+        let prefix = pred_prefix(&common, i);
 
         self.hb_zero_from_prefix(&prefix, e_i, z_i)
     }
 
     pub fn verifier_finalize_common_h(&mut self) -> Result<()> {
-        let mut buf = Vec::new();
-        buf.extend_from_slice(b"mode:FISCHLIN|x|"); buf.extend_from_slice(&self.statement_bytes);
-        buf.extend_from_slice(b"|m_vec|");
-        for (i, m) in self.m_vec.iter().enumerate() {
-            buf.extend_from_slice(b"i="); buf.extend_from_slice(&(i as u32).to_le_bytes());
-            buf.extend_from_slice(b":m="); buf.extend_from_slice(m); buf.push(0xff);
-        }
-        buf.extend_from_slice(b"|sid|"); buf.extend_from_slice(&self.sid_bytes);
-        self.common_h = Some(self.ro.H_full("fischlin.common", &buf));
+        self.common_h = Some(self.ro.H_full("fischlin.common", &self.common_buf));
         Ok(())
     }
     pub fn begin_verifier(&mut self, statement: &[u8], sid: &[u8]) {
         self.phase = Phase::CollectingFirstMsgs;
-        self.statement_bytes = statement.to_vec();
-        self.sid_bytes = sid.to_vec();
-        self.m_vec.clear();
-        self.common_h = None;
+        self.start_common(statement, sid);
         self.scratch.clear();
         self.absorb("mode", b"FISCHLIN");
         self.absorb("x", statement);
@@ -279,7 +246,7 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
         if !matches!(self.phase, Phase::CollectingFirstMsgs) {
             return Err(ProveError::Malformed("fischlin: verifier push_first_message before begin"));
         }
-        self.m_vec.push(m_i.to_vec());
+        self.push_common(m_i);
         self.absorb("m_i", m_i);
         Ok(())
     }
@@ -287,13 +254,19 @@ impl<RO: RandomOracle> FischlinOracle<RO> {
 
 impl<RO: RandomOracle> TranscriptRuntime for FischlinOracle<RO> {
     fn absorb(&mut self, label: &'static str, bytes: &[u8]) {
-        self.transcript_buf.extend_from_slice(b"|label|");
-        self.transcript_buf.extend_from_slice(label.as_bytes());
-        self.transcript_buf.extend_from_slice(b"|data|");
-        self.transcript_buf.extend_from_slice(bytes);
+        framing::frame(&mut self.transcript_buf, op::ABSORB, label.as_bytes(), bytes);
     }
 }
 
+/// Per-repetition predicate prefix: frame(common_h) || frame(i).
+#[inline]
+fn pred_prefix(common_h: &[u8], i: u32) -> Vec<u8> {
+    let mut prefix = Vec::with_capacity(framing::framed_len(b"pred", common_h) + framing::framed_len(b"i", &[0; 4]));
+    framing::frame(&mut prefix, op::DOMAIN, b"pred", common_h);
+    framing::frame(&mut prefix, op::ABSORB, b"i", &i.to_le_bytes());
+    prefix
+}
+
 #[inline]
 fn encode_e(e_val: u64, t: u8) -> Vec<u8> {
     let blen = ((t as usize + 7) / 8).max(1);
@@ -0,0 +1,83 @@
+//! Injective transcript framing shared by every backend.
+//!
+//! Each transcript operation is serialized as
+//!
+//! ```text
+//! op (1 byte) || len(label) (u64 LE) || label || len(data) (u64 LE) || data
+//! ```
+//!
+//! A frame is self-delimiting, so a concatenation of frames parses back into exactly one sequence
+//! of `(op, label, data)` triples: two different absorb sequences never produce the same hash
+//! input, whatever bytes the labels or data contain. `HashOracle`, `FSOracle`, `FischlinOracle`
+//! and the `hashes` / `sponge` backends all hash frames; `PoseidonOracle` uses the same op codes
+//! with field-element length prefixes. `MerlinOracle` keeps Merlin's own STROBE framing (already
+//! length-prefixed) so that it stays byte-compatible with Merlin.
+
+/// Operation types.
+pub mod op {
+    /// Oracle domain / mode separator.
+    pub const DOMAIN: u8 = 0x00;
+    /// Transcript absorb.
+    pub const ABSORB: u8 = 0x01;
+    /// Typed challenge (`Oracle::challenge`); data is the output length.
+    pub const CHALLENGE: u8 = 0x02;
+    /// `RandomOracle::H_full` input.
+    pub const H_FULL: u8 = 0x03;
+    /// `RandomOracle::H` input (Fischlin predicate).
+    pub const H: u8 = 0x04;
+    /// `RandomOracle::H_xof` input; data is `out_len (u64 LE) || input`.
+    pub const H_XOF: u8 = 0x05;
+    /// Native field elements (`PoseidonOracle::absorb_field`).
+    pub const ABSORB_FIELD: u8 = 0x06;
+    /// Caller-supplied extra input to an FS challenge.
+    pub const EXTRA: u8 = 0x07;
+}
+
+/// Feed one frame to `sink` piecewise (e.g. straight into a sponge, without a temporary buffer).
+#[inline]
+pub fn write_frame(mut sink: impl FnMut(&[u8]), op: u8, label: &[u8], data: &[u8]) {
+    sink(&[op]);
+    sink(&(label.len() as u64).to_le_bytes());
+    sink(label);
+    sink(&(data.len() as u64).to_le_bytes());
+    sink(data);
+}
+
+/// Append one frame to `out`.
+#[inline]
+pub fn frame(out: &mut Vec<u8>, op: u8, label: &[u8], data: &[u8]) {
+    out.reserve(framed_len(label, data));
+    write_frame(|b| out.extend_from_slice(b), op, label, data);
+}
+
+/// One frame as a fresh buffer.
+pub fn framed(op: u8, label: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    frame(&mut out, op, label, data);
+    out
+}
+
+/// Encoded length of a frame.
+#[inline]
+pub const fn framed_len(label: &[u8], data: &[u8]) -> usize {
+    1 + 8 + label.len() + 8 + data.len()
+}
+
+/// Split the first frame off `input`: `(op, label, data)`, or `None` if `input` is truncated.
+pub fn parse_frame<'a>(input: &mut &'a [u8]) -> Option<(u8, &'a [u8], &'a [u8])> {
+    fn take<'a>(input: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+        if input.len() < n { return None; }
+        let (head, rest) = input.split_at(n);
+        *input = rest;
+        Some(head)
+    }
+    fn take_len(input: &mut &[u8]) -> Option<usize> {
+        usize::try_from(u64::from_le_bytes(take(input, 8)?.try_into().ok()?)).ok()
+    }
+    let op = take(input, 1)?[0];
+    let label_len = take_len(input)?;
+    let label = take(input, label_len)?;
+    let data_len = take_len(input)?;
+    let data = take(input, data_len)?;
+    Some((op, label, data))
+}
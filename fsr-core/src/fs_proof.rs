@@ -81,6 +81,7 @@ pub fn verify_fs<RO, F>(
     statement: impl AsRef<[u8]>,
     sid: impl AsRef<[u8]>,
     proof: &FsProof,
+    chal_len: usize,
     mut sigma_verify: F,
 ) -> bool
 where
@@ -102,8 +103,7 @@ where
         oracle.absorb("m_i", m_i);
     }
 
-    // Fixed-length challenge derived from the transcript; ignore proof.b
-    let chal_len = 32usize;
+    // Challenge length is fixed by the protocol (not by the proof); ignore proof.b
     for (i, (m_i, z_i)) in proof.m.iter().zip(proof.z.iter()).enumerate() {
         let e_bytes = oracle.derive_challenge("e_i", &[], chal_len);
         oracle.absorb("e_i", &e_bytes);
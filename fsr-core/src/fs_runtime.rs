@@ -1,42 +1,56 @@
 //! Fiat–Shamir runtime that shares the TranscriptRuntime base.
+//!
+//! The transcript is kept as a running chaining value: absorbed frames are buffered only until
+//! the next challenge, which folds them in with one `H_full` call,
+//! `chain' = H_full("fs.chain", frame(chain) || pending frames)`. Each absorbed byte is hashed
+//! once, and a challenge costs O(bytes absorbed since the previous challenge), not O(transcript).
 
+use crate::framing::{self, op};
 use crate::runtime::{TranscriptRuntime, RandomOracle};
 
 pub struct FSOracle<RO: RandomOracle> {
     ro: RO,
-    // internal transcript buffer or state accumulator
-    buf: Vec<u8>,
+    // chaining value over everything folded so far (empty before the first fold)
+    chain: Vec<u8>,
+    // frames absorbed since the last fold
+    pending: Vec<u8>,
 }
 
 impl<RO: RandomOracle> FSOracle<RO> {
     pub fn new(ro: RO) -> Self {
-        Self { ro, buf: Vec::new() }
+        Self { ro, chain: Vec::new(), pending: Vec::new() }
     }
 
     /// Derive a challenge from the current transcript state (+ optional extra).
-    /// `out_len` is the number of bytes you want (your DSL/codec will parse it).
+    /// `out_len` is the number of bytes you want (your DSL/codec will parse it); any length is
+    /// honoured via `RandomOracle::H_xof`, and different lengths give unrelated outputs.
     pub fn derive_challenge(&mut self, label: &'static str, extra: &[u8], out_len: usize) -> Vec<u8> {
-        let mut m = Vec::with_capacity(self.buf.len() + 32 + extra.len());
-        m.extend_from_slice(b"mode:FS|");
-        m.extend_from_slice(&self.buf);
-        if !extra.is_empty() {
-            m.extend_from_slice(b"|extra|");
-            m.extend_from_slice(extra);
-        }
-        let h = self.ro.H_full(label, &m);
-        h[..out_len.min(h.len())].to_vec()
+        self.fold();
+        let mut m = Vec::with_capacity(self.chain.len() + framing::framed_len(b"extra", extra) + 48);
+        framing::frame(&mut m, op::DOMAIN, b"mode", b"FS");
+        framing::frame(&mut m, op::ABSORB, b"chain", &self.chain);
+        framing::frame(&mut m, op::EXTRA, b"extra", extra);
+        self.ro.H_xof(label, &m, out_len)
+    }
+
+    /// Fold pending frames into the chaining value.
+    fn fold(&mut self) {
+        if self.pending.is_empty() { return; }
+        let mut m = framing::framed(op::DOMAIN, b"chain", &self.chain);
+        m.append(&mut self.pending);
+        self.chain = self.ro.H_full("fs.chain", &m);
     }
 
     /// Optional: reset between proofs.
-    pub fn reset(&mut self) { self.buf.clear(); }
+    pub fn reset(&mut self) {
+        self.chain.clear();
+        self.pending.clear();
+    }
 }
 
 impl<RO: RandomOracle> TranscriptRuntime for FSOracle<RO> {
     fn absorb(&mut self, label: &'static str, bytes: &[u8]) {
         // Domain-separated monotone absorption.
-        self.buf.extend_from_slice(b"|label|");
-        self.buf.extend_from_slice(label.as_bytes());
-        self.buf.extend_from_slice(b"|data|");
-        self.buf.extend_from_slice(bytes);
+        framing::frame(&mut self.pending, op::ABSORB, label.as_bytes(), bytes);
     }
 }
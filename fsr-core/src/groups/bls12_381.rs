@@ -0,0 +1,38 @@
+//! BLS12-381 over arkworks (feature = "bls12_381", implies "ark").
+//!
+//! The codec, `Challenge`, `PrimeField` and `PrimeGroup` impls are the generic ones in
+//! `crate::ark`; for this curve that means:
+//!
+//! * `G1Affine` / `G1Projective` encode as 48-byte and `G2Affine` / `G2Projective` as 96-byte
+//!   compressed points (the zcash / IETF BLS flag layout). Decoding checks that the point is on
+//!   the curve *and* in the prime-order subgroup, so small-subgroup components never reach a
+//!   transcript or a verification equation.
+//! * `Gt` (the pairing target group, written additively like arkworks' `PairingOutput`) encodes as
+//!   its 576-byte Fq12 value; decoding checks every coefficient is canonical and that the element
+//!   has order r.
+//! * `Scalar` (Fr) encodes as 32 little-endian bytes; decoding rejects values ≥ r. As a
+//!   `Challenge` it reduces |r| + 128 oracle bits mod r.
+//! * `G1Projective`, `G2Projective` and `Gt` are `PrimeGroup`s over `Scalar`; `Gt`'s generator is
+//!   e(G1, G2).
+
+use ark_ec::pairing::{Pairing, PairingOutput};
+
+pub use ark_bls12_381::{Bls12_381, Fr as Scalar, G1Affine, G1Projective, G2Affine, G2Projective};
+pub use ark_ec::Group;
+
+/// The pairing target group.
+pub type Gt = PairingOutput<Bls12_381>;
+
+/// Encoded size of a G1 point.
+pub const G1_BYTES: usize = 48;
+/// Encoded size of a G2 point.
+pub const G2_BYTES: usize = 96;
+/// Encoded size of a GT element.
+pub const GT_BYTES: usize = 576;
+/// Encoded size of a scalar.
+pub const SCALAR_BYTES: usize = 32;
+
+/// e(p, q).
+pub fn pairing(p: impl Into<G1Affine>, q: impl Into<G2Affine>) -> Gt {
+    Bls12_381::pairing(p.into(), q.into())
+}
@@ -0,0 +1,163 @@
+//! Prime-order group backends (feature-gated).
+//!
+//! Each module implements `CanonicalEncode`, strict `CanonicalDecode` and `Challenge` directly on
+//! the backend crate's point and scalar types, so they can be used as fields of `FsrBindable`
+//! messages, `proof!` specs and `Transcript` challenges without wrappers.
+//!
+//! Codec contract: every group element other than the identity round-trips through
+//! `CanonicalEncode` / `CanonicalDecode`. The identity need not: SEC1 compressed points have no
+//! encoding for it, so the secp256k1 and P-256 backends write it as 33 zero bytes and refuse those
+//! bytes on decode (the other backends round-trip it). Protocols must not put the identity into a
+//! transcript or proof; honest commitments hit it with negligible probability, and a proof
+//! carrying it is rejected.
+//!
+//! `PrimeGroup` / `PrimeField` are the arithmetic every backend shares (implemented on each
+//! backend's types), so protocols, `proof!` check blocks and `respond_stream` closures can be
+//! written once and instantiated on any backend:
+//!
+//! ```ignore
+//! fn schnorr_check<G: PrimeGroup>(y: G, t: G, e: G::Scalar, z: G::Scalar) -> bool {
+//!     G::generator() * z == t + y * e
+//! }
+//! ```
+
+use core::fmt::Debug;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use rand_core::RngCore;
+
+use crate::{CanonicalDecode, CanonicalEncode, Challenge};
+
+/// A prime field of scalars: canonical encoding, and a `Challenge` (oracle bytes reduced to a
+/// near-uniform element).
+pub trait PrimeField:
+    Copy + Eq + Debug + Send + Sync + 'static
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+    + CanonicalEncode + CanonicalDecode + Challenge
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_u64(v: u64) -> Self;
+    /// Multiplicative inverse; `None` for zero.
+    fn invert(&self) -> Option<Self>;
+
+    /// A uniformly random element, by the same reduction as challenges.
+    fn random<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = vec![0u8; Self::BYTES];
+        rng.fill_bytes(&mut bytes);
+        Self::from_oracle_bytes("random", &bytes)
+    }
+}
+
+/// A prime-order group, written additively, with scalars `Self::Scalar`. Encodings follow the
+/// module's codec contract: the identity need not round-trip and must stay out of transcripts.
+pub trait PrimeGroup:
+    Copy + Eq + Debug + Send + Sync + 'static
+    + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self> + Mul<<Self as PrimeGroup>::Scalar, Output = Self>
+    + CanonicalEncode + CanonicalDecode
+{
+    type Scalar: PrimeField;
+
+    /// The backend's fixed generator.
+    fn generator() -> Self;
+    fn identity() -> Self;
+    fn is_identity(&self) -> bool { *self == Self::identity() }
+
+    /// Σ s_k·P_k. Default: one multiplication per term; backends with a multi-scalar
+    /// multiplication (Straus / Pippenger) override it.
+    fn msm(terms: &[(Self::Scalar, Self)]) -> Self {
+        terms.iter().fold(Self::identity(), |acc, (s, p)| acc + *p * *s)
+    }
+}
+
+/// Codec and `Challenge` impls for a RustCrypto `elliptic-curve` crate (`k256`, `p256`):
+/// SEC1 compressed points, big-endian scalars, `WideReduction<$order>` challenges.
+#[cfg(any(feature = "secp256k1", feature = "p256"))]
+macro_rules! sec1_group_impls {
+    ($krate:ident, $order:ty) => {
+        use ::$krate::elliptic_curve::group::GroupEncoding;
+        use ::$krate::elliptic_curve::PrimeField;
+        use ::$krate::FieldBytes;
+
+        use crate::challenge::WideReduction;
+        use crate::groups::{PrimeField as FsrPrimeField, PrimeGroup};
+        use crate::{CanonicalDecode, CanonicalEncode, Challenge};
+
+        pub use ::$krate::{AffinePoint, ProjectivePoint, Scalar};
+
+        /// Encoded size of a point (SEC1 compressed).
+        pub const POINT_BYTES: usize = 33;
+        /// Encoded size of a scalar (big-endian).
+        pub const SCALAR_BYTES: usize = 32;
+
+        fn decode_point<P: GroupEncoding>(input: &mut &[u8]) -> Option<P> {
+            let bytes = input.get(..POINT_BYTES)?;
+            // `from_bytes` also maps 33 zero bytes (what `to_bytes` writes for the identity) to
+            // the identity, which is not a SEC1 compressed point; only the 0x02 / 0x03 tags are
+            // accepted, so the identity does not round-trip (see the module docs).
+            if !matches!(bytes[0], 0x02 | 0x03) { return None; }
+            let mut repr = P::Repr::default();
+            repr.as_mut().copy_from_slice(bytes);
+            let p = Option::from(P::from_bytes(&repr))?;
+            *input = &input[POINT_BYTES..];
+            Some(p)
+        }
+
+        impl CanonicalEncode for ProjectivePoint {
+            fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.to_bytes()); }
+        }
+        impl CanonicalDecode for ProjectivePoint {
+            fn decode(input: &mut &[u8]) -> Option<Self> { decode_point(input) }
+        }
+
+        impl CanonicalEncode for AffinePoint {
+            fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.to_bytes()); }
+        }
+        impl CanonicalDecode for AffinePoint {
+            fn decode(input: &mut &[u8]) -> Option<Self> { decode_point(input) }
+        }
+
+        impl CanonicalEncode for Scalar {
+            fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.to_repr()); }
+        }
+        impl CanonicalDecode for Scalar {
+            fn decode(input: &mut &[u8]) -> Option<Self> {
+                let bytes = input.get(..SCALAR_BYTES)?;
+                let s = Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(bytes)))?;
+                *input = &input[SCALAR_BYTES..];
+                Some(s)
+            }
+        }
+
+        impl Challenge for Scalar {
+            const BYTES: usize = WideReduction::<$order>::BYTES;
+            fn from_oracle_bytes(label: &str, input: &[u8]) -> Self {
+                let mut be = WideReduction::<$order>::from_oracle_bytes(label, input).0.to_le_bytes();
+                be.reverse();
+                Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(&be))).expect("reduced below the order")
+            }
+        }
+
+        impl FsrPrimeField for Scalar {
+            fn zero() -> Self { Scalar::ZERO }
+            fn one() -> Self { Scalar::ONE }
+            fn from_u64(v: u64) -> Self { Scalar::from(v) }
+            fn invert(&self) -> Option<Self> { Option::from(::$krate::elliptic_curve::Field::invert(self)) }
+        }
+
+        impl PrimeGroup for ProjectivePoint {
+            type Scalar = Scalar;
+            fn generator() -> Self { ProjectivePoint::GENERATOR }
+            fn identity() -> Self { ProjectivePoint::IDENTITY }
+        }
+    };
+}
+
+#[cfg(feature = "ristretto")]
+pub mod ristretto;    // Ristretto255 (curve25519-dalek)
+#[cfg(feature = "secp256k1")]
+pub mod secp256k1;    // secp256k1 (k256), SEC1 compressed points
+#[cfg(feature = "p256")]
+pub mod p256;         // NIST P-256 (p256), SEC1 compressed points
+#[cfg(feature = "bls12_381")]
+pub mod bls12_381;    // BLS12-381 G1 / G2 / GT (arkworks), subgroup-checked decoding
@@ -0,0 +1,10 @@
+//! NIST P-256 over the `p256` crate (feature = "p256").
+//!
+//! Same encodings as `groups::secp256k1`: 33-byte SEC1 compressed points (the identity is written
+//! as 33 zero bytes and rejected on decode), 32-byte big-endian scalars below n, and
+//! `WideReduction<P256Order>` challenges. Points decoded here are the keys WebAuthn
+//! authenticators and HSMs export in compressed form.
+
+use crate::challenge::P256Order;
+
+sec1_group_impls!(p256, P256Order);
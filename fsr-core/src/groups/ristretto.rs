@@ -0,0 +1,83 @@
+//! Ristretto255 over curve25519-dalek (feature = "ristretto").
+//!
+//! * `RistrettoPoint` encodes as its 32-byte compressed form. Decoding rejects anything that is
+//!   not the canonical encoding of a group element (Ristretto decompression is strict), so every
+//!   point has exactly one transcript encoding.
+//! * `Scalar` encodes as 32 little-endian bytes; decoding rejects values ≥ ℓ.
+//! * `Scalar` is a `Challenge` via `WideReduction<Ed25519Order>`: |ℓ| + 128 oracle bits reduced
+//!   mod ℓ.
+//! * `PrimeGroup::msm` is dalek's variable-time multiscalar multiplication (verifier-side only:
+//!   the scalars must be public).
+
+use curve25519_dalek::ristretto::CompressedRistretto;
+use curve25519_dalek::traits::{Identity, VartimeMultiscalarMul};
+
+use crate::challenge::{Ed25519Order, WideReduction};
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::{CanonicalDecode, CanonicalEncode, Challenge};
+
+pub use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT as BASEPOINT;
+pub use curve25519_dalek::ristretto::RistrettoPoint;
+pub use curve25519_dalek::scalar::Scalar;
+
+/// Encoded size of a point.
+pub const POINT_BYTES: usize = 32;
+/// Encoded size of a scalar.
+pub const SCALAR_BYTES: usize = 32;
+
+fn take<const N: usize>(input: &mut &[u8]) -> Option<[u8; N]> {
+    let bytes: [u8; N] = input.get(..N)?.try_into().ok()?;
+    *input = &input[N..];
+    Some(bytes)
+}
+
+impl CanonicalEncode for RistrettoPoint {
+    fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(self.compress().as_bytes()); }
+}
+
+impl CanonicalDecode for RistrettoPoint {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let mut peek = *input;
+        let p = CompressedRistretto(take::<POINT_BYTES>(&mut peek)?).decompress()?;
+        *input = peek;
+        Some(p)
+    }
+}
+
+impl CanonicalEncode for Scalar {
+    fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(self.as_bytes()); }
+}
+
+impl CanonicalDecode for Scalar {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let mut peek = *input;
+        let s = Option::from(Scalar::from_canonical_bytes(take::<SCALAR_BYTES>(&mut peek)?))?;
+        *input = peek;
+        Some(s)
+    }
+}
+
+impl Challenge for Scalar {
+    const BYTES: usize = WideReduction::<Ed25519Order>::BYTES;
+    fn from_oracle_bytes(label: &str, input: &[u8]) -> Self {
+        let e = WideReduction::<Ed25519Order>::from_oracle_bytes(label, input).0;
+        let bytes: [u8; SCALAR_BYTES] = e.to_le_bytes().try_into().expect("ℓ is 253 bits");
+        Scalar::from_bytes_mod_order(bytes)
+    }
+}
+
+impl PrimeField for Scalar {
+    fn zero() -> Self { Scalar::ZERO }
+    fn one() -> Self { Scalar::ONE }
+    fn from_u64(v: u64) -> Self { Scalar::from(v) }
+    fn invert(&self) -> Option<Self> { (*self != Scalar::ZERO).then(|| Scalar::invert(self)) }
+}
+
+impl PrimeGroup for RistrettoPoint {
+    type Scalar = Scalar;
+    fn generator() -> Self { BASEPOINT }
+    fn identity() -> Self { <RistrettoPoint as Identity>::identity() }
+    fn msm(terms: &[(Scalar, Self)]) -> Self {
+        RistrettoPoint::vartime_multiscalar_mul(terms.iter().map(|(s, _)| s), terms.iter().map(|(_, p)| p))
+    }
+}
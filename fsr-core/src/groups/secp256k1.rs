@@ -0,0 +1,13 @@
+//! secp256k1 over k256 (feature = "secp256k1").
+//!
+//! * `ProjectivePoint` / `AffinePoint` encode as 33-byte SEC1 compressed points. Decoding accepts
+//!   only a 0x02/0x03 tag with a canonical x-coordinate on the curve; the identity has no
+//!   compressed encoding (it is written as 33 zero bytes) and is rejected, so it does not
+//!   round-trip.
+//! * `Scalar` encodes as 32 big-endian bytes (the SEC1 / wallet convention); decoding rejects
+//!   values ≥ n.
+//! * `Scalar` is a `Challenge` via `WideReduction<Secp256k1Order>`.
+
+use crate::challenge::Secp256k1Order;
+
+sec1_group_impls!(k256, Secp256k1Order);
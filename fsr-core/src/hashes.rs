@@ -0,0 +1,163 @@
+//! Cryptographic hash backends (feature-gated).
+//!
+//! * `DigestOracle<D>` — any RustCrypto fixed-output `digest::Digest` (SHA-256, SHA-512, SHA3-256, ...).
+//! * `XofOracle<X>` — any RustCrypto `digest::ExtendableOutput` (SHAKE128/256, ...).
+//! * `Blake3Oracle` — BLAKE3 in XOF mode.
+//!
+//! Each backend is a drop-in replacement for `HashOracle`: it implements `Absorb` + `Oracle`
+//! for the typed `Transcript<PENDING, O>` and `RandomOracle` for `FSOracle` / `FischlinOracle`.
+//! Hash inputs are `framing` frames: frame(domain) || absorbed frames || frame(op, label, data).
+//! The hash state runs over the transcript as it is absorbed; every output clones it and hashes
+//! only the final frame, so its cost does not depend on the transcript length. `H_full` and `H`
+//! use different op codes, so truncating `H` for Fischlin's predicate never reuses an `H_full`
+//! output.
+
+use crate::framing::{self, op};
+use crate::runtime::RandomOracle;
+use crate::{Absorb, CanonicalEncode, Challenge, Oracle, Result};
+
+// Output length of `H_full` / `H` for every backend.
+const RO_BYTES: usize = 32;
+
+/// Shared constructor and `Absorb` / `Oracle` / `RandomOracle` impls for an oracle whose running
+/// hash state is the field `state`. `$update(&mut state, bytes)` absorbs into the state and
+/// `$finish(state, out_len)` produces `out_len` bytes from a (cloned) state.
+macro_rules! hash_oracle_impls {
+    (impl[$($g:tt)*] $ty:ty, $update:expr, $finish:expr) => {
+        impl<$($g)*> $ty {
+            pub fn new(domain: &'static [u8]) -> Self {
+                let mut state = Default::default();
+                framing::write_frame(|b| $update(&mut state, b), op::DOMAIN, b"domain", domain);
+                Self { state }
+            }
+
+            fn digest(&self, op: u8, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+                let mut state = self.state.clone();
+                framing::write_frame(|b| $update(&mut state, b), op, label.as_bytes(), data);
+                $finish(state, out_len)
+            }
+        }
+
+        impl<$($g)*> Absorb for $ty {
+            fn absorb_bytes(&mut self, label: &'static str, bytes: &[u8]) {
+                framing::write_frame(|b| $update(&mut self.state, b), op::ABSORB, label.as_bytes(), bytes);
+            }
+        }
+
+        impl<$($g)*> Oracle for $ty {
+            fn challenge<C: Challenge + CanonicalEncode>(&mut self, label: &'static str) -> Result<C> {
+                let out = self.digest(op::CHALLENGE, label, &(C::BYTES as u64).to_le_bytes(), C::BYTES);
+                Ok(C::from_oracle_bytes(label, &out))
+            }
+        }
+
+        impl<$($g)*> RandomOracle for $ty {
+            fn H_full(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+                self.digest(op::H_FULL, label, data, RO_BYTES)
+            }
+
+            fn H(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+                self.digest(op::H, label, data, RO_BYTES)
+            }
+
+            fn H_xof(&mut self, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+                let mut input = (out_len as u64).to_le_bytes().to_vec();
+                input.extend_from_slice(data);
+                self.digest(op::H_XOF, label, &input, out_len)
+            }
+        }
+    };
+}
+
+// ---------------- Generic RustCrypto adapters ----------------
+
+/// Random oracle over any fixed-output RustCrypto hash, e.g. `DigestOracle<sha2::Sha512>`.
+/// Outputs longer than the digest size use counter-mode expansion:
+/// block_i = D(input || i_le32).
+#[cfg(feature = "digest")]
+#[derive(Clone)]
+pub struct DigestOracle<D> {
+    state: D,
+}
+
+#[cfg(feature = "digest")]
+fn finish_digest<D: digest::Digest + Clone>(state: D, out_len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(out_len);
+    let mut ctr: u32 = 0;
+    while out.len() < out_len {
+        let mut d = state.clone();
+        d.update(ctr.to_le_bytes());
+        out.extend_from_slice(&d.finalize());
+        ctr += 1;
+    }
+    out.truncate(out_len);
+    out
+}
+
+#[cfg(feature = "digest")]
+hash_oracle_impls!(
+    impl[D: digest::Digest + Default + Clone] DigestOracle<D>,
+    |d: &mut D, b: &[u8]| digest::Digest::update(d, b),
+    finish_digest::<D>
+);
+
+/// Random oracle over any RustCrypto extendable-output function, e.g. `XofOracle<sha3::Shake256>`.
+/// Challenges of any length are read directly from the XOF.
+#[cfg(feature = "digest")]
+#[derive(Clone)]
+pub struct XofOracle<X> {
+    state: X,
+}
+
+#[cfg(feature = "digest")]
+fn finish_xof<X: digest::ExtendableOutput>(state: X, out_len: usize) -> Vec<u8> {
+    use digest::XofReader;
+    let mut out = vec![0u8; out_len];
+    state.finalize_xof().read(&mut out);
+    out
+}
+
+#[cfg(feature = "digest")]
+hash_oracle_impls!(
+    impl[X: digest::ExtendableOutput + digest::Update + Default + Clone] XofOracle<X>,
+    |x: &mut X, b: &[u8]| digest::Update::update(x, b),
+    finish_xof::<X>
+);
+
+// ---------------- Named instantiations ----------------
+
+/// SHA-256 backend. Outputs longer than 32 bytes use counter-mode expansion.
+#[cfg(feature = "sha2")]
+pub type Sha256Oracle = DigestOracle<sha2::Sha256>;
+
+/// SHA3-256 backend. Outputs longer than 32 bytes use counter-mode expansion.
+#[cfg(feature = "sha3")]
+pub type Sha3Oracle = DigestOracle<sha3::Sha3_256>;
+
+/// SHAKE256 backend; challenges of any length are read directly from the XOF.
+#[cfg(feature = "sha3")]
+pub type Shake256Oracle = XofOracle<sha3::Shake256>;
+
+// ---------------- BLAKE3 ----------------
+
+/// BLAKE3 backend; challenges of any length are read directly from the XOF.
+/// (BLAKE3 implements a different `digest` major version, so it has its own type.)
+#[cfg(feature = "blake3")]
+#[derive(Clone)]
+pub struct Blake3Oracle {
+    state: blake3::Hasher,
+}
+
+#[cfg(feature = "blake3")]
+fn finish_blake3(state: blake3::Hasher, out_len: usize) -> Vec<u8> {
+    let mut out = vec![0u8; out_len];
+    state.finalize_xof().fill(&mut out);
+    out
+}
+
+#[cfg(feature = "blake3")]
+hash_oracle_impls!(
+    impl[] Blake3Oracle,
+    |h: &mut blake3::Hasher, b: &[u8]| { h.update(b); },
+    finish_blake3
+);
@@ -7,16 +7,54 @@ pub use fsr_bind_derive::FsrBindable;
 
 // Additional modules
 pub mod runtime;      // TranscriptRuntime + RandomOracle
+pub mod framing;      // injective (op, label, data) transcript framing
 pub mod fs_runtime;   // FS (Fiat–Shamir) oracle
 pub mod fischlin;     // Fischlin oracle + params
 pub mod fischlin_proof; // Fischlin proof encoding + verify helpers
 pub mod fs_proof;
+pub mod challenge;    // unbiased challenge reductions (wide, rejection, b-bit)
+pub mod groups;       // prime-order group backends (feature-gated submodules)
+pub mod sigma;        // SigmaProtocol trait + generic FS / Fischlin drivers
+pub mod batch;        // batch verification of FS proofs for linear sigma protocols (one MSM, bisection)
+pub mod protocols;    // SigmaProtocol implementations (Schnorr, ...)
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;      // test harnesses: special-soundness extraction, HVZK simulation, a toy group
+#[cfg(feature = "ark")]
+pub mod ark;          // arkworks interop: ark-serialize codec, field challenges, group traits
+#[cfg(any(feature = "digest", feature = "blake3"))]
+pub mod hashes;       // RustCrypto digest/XOF adapters + BLAKE3 random oracles
+#[cfg(feature = "sponge")]
+pub mod sponge;       // Keccak-f[1600] duplex-sponge transcript
+#[cfg(feature = "merlin")]
+pub mod merlin;       // Merlin/STROBE-128 compatible transcript
+#[cfg(feature = "poseidon")]
+pub mod poseidon;     // Poseidon (algebraic) random oracle over BN254 / BLS12-381 scalars
 
 pub use runtime::{TranscriptRuntime, RandomOracle};
 pub use fs_runtime::FSOracle;
 pub use fischlin::{FischlinOracle, FischlinParams};
 pub use fischlin_proof::{FischlinProof, verify_fischlin};
 pub use fs_proof::FsProof;
+pub use challenge::{Modulus, RejectionSampled, ScalarMod, TruncatedBits, WideReduction};
+pub use groups::{PrimeField, PrimeGroup};
+pub use sigma::SigmaProtocol;
+pub use batch::LinearSigma;
+#[cfg(feature = "ark")]
+pub use ark::Ark;
+#[cfg(feature = "digest")]
+pub use hashes::{DigestOracle, XofOracle};
+#[cfg(feature = "sha2")]
+pub use hashes::Sha256Oracle;
+#[cfg(feature = "sha3")]
+pub use hashes::{Sha3Oracle, Shake256Oracle};
+#[cfg(feature = "blake3")]
+pub use hashes::Blake3Oracle;
+#[cfg(feature = "sponge")]
+pub use sponge::{KeccakSponge, SpongeOracle};
+#[cfg(feature = "merlin")]
+pub use merlin::MerlinOracle;
+#[cfg(feature = "poseidon")]
+pub use poseidon::{PoseidonBls12_381, PoseidonBn254, PoseidonOracle};
 
 // Qualify of life improvements
 pub mod error;
@@ -71,25 +109,94 @@ impl CanonicalDecode for Vec<u8> {
     }
 }
 
+// Composite values (statements, commitments, responses of composed sigma protocols): pairs are
+// the concatenation of their parts, vectors a u32 count followed by the items, options a 0 / 1
+// tag byte followed by the item when present.
+impl<A: CanonicalEncode, B: CanonicalEncode> CanonicalEncode for (A, B) {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.0.encode(out);
+        self.1.encode(out);
+    }
+}
+impl<A: CanonicalDecode, B: CanonicalDecode> CanonicalDecode for (A, B) {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let mut rest = *input;
+        let a = A::decode(&mut rest)?;
+        let b = B::decode(&mut rest)?;
+        *input = rest;
+        Some((a, b))
+    }
+}
+
+impl<T: CanonicalEncode> CanonicalEncode for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(item) => {
+                out.push(1);
+                item.encode(out);
+            }
+        }
+    }
+}
+impl<T: CanonicalDecode> CanonicalDecode for Option<T> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let (&tag, mut rest) = input.split_first()?;
+        let v = match tag {
+            0 => None,
+            1 => Some(T::decode(&mut rest)?),
+            _ => return None,
+        };
+        *input = rest;
+        Some(v)
+    }
+}
+
+impl<T: CanonicalEncode> CanonicalEncode for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&(self.len() as u32).to_le_bytes());
+        for item in self { item.encode(out); }
+    }
+}
+impl<T: CanonicalDecode> CanonicalDecode for Vec<T> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        if input.len() < 4 { return None; }
+        let mut lenb = [0u8; 4];
+        lenb.copy_from_slice(&input[..4]);
+        let len = u32::from_le_bytes(lenb) as usize;
+        let mut rest = &input[4..];
+        // The count is untrusted: grow as items actually decode.
+        let mut v = Vec::with_capacity(len.min(rest.len()));
+        for _ in 0..len { v.push(T::decode(&mut rest)?); }
+        *input = rest;
+        Some(v)
+    }
+}
+
 pub trait Absorb {
     fn absorb_bytes(&mut self, label: &'static str, bytes: &[u8]);
 }
 
+// A borrowed oracle is an oracle, so a `Transcript` can run on `&mut O` and the caller keeps O.
+impl<A: Absorb + ?Sized> Absorb for &mut A {
+    fn absorb_bytes(&mut self, label: &'static str, bytes: &[u8]) { (**self).absorb_bytes(label, bytes) }
+}
+
+/// A value derivable from oracle output. Oracles produce exactly `BYTES` uniform bytes and hand
+/// them to `from_oracle_bytes`; see `challenge` for unbiased reductions into a modulus.
 pub trait Challenge: Sized {
     fn from_oracle_bytes(domain_label: &str, input: &[u8]) -> Self;
     const BYTES: usize;
 }
 
+/// A full 64-bit challenge (the first 8 oracle bytes, little-endian).
 #[derive(Clone, Copy, Debug)]
 pub struct U64Challenge(pub u64);
 impl Challenge for U64Challenge {
-    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self {
-        use core::hash::{Hash, Hasher};
-        let mut s = std::collections::hash_map::DefaultHasher::new();
-        input.hash(&mut s);
-        U64Challenge(s.finish())
+    fn from_oracle_bytes(label: &str, input: &[u8]) -> Self {
+        U64Challenge(TruncatedBits::<64>::from_oracle_bytes(label, input).0)
     }
-    const BYTES: usize = 32;
+    const BYTES: usize = 8;
 }
 
 impl CanonicalEncode for U64Challenge {
@@ -112,58 +219,69 @@ pub trait Oracle: Absorb {
     fn challenge<C: Challenge + CanonicalEncode>(&mut self, label: &'static str) -> Result<C>;
 }
 
+impl<O: Oracle + ?Sized> Oracle for &mut O {
+    fn challenge<C: Challenge + CanonicalEncode>(&mut self, label: &'static str) -> Result<C> { (**self).challenge(label) }
+}
+
+use core::hash::Hasher;
+use std::collections::hash_map::DefaultHasher;
+
+/// Demo oracle over std's `DefaultHasher` (not cryptographic). The hasher runs over the framed
+/// transcript as it is absorbed; a challenge clones it and hashes only the final frame.
+#[derive(Clone)]
 pub struct HashOracle {
-    buf: Vec<u8>,
-    domain: &'static [u8],
+    state: DefaultHasher,
 }
 impl HashOracle {
-    pub fn new(domain: &'static [u8]) -> Self { Self { buf: Vec::new(), domain } }
+    pub fn new(domain: &'static [u8]) -> Self {
+        let mut state = DefaultHasher::new();
+        framing::write_frame(|b| state.write(b), framing::op::DOMAIN, b"domain", domain);
+        Self { state }
+    }
+
+    /// `out_len` bytes bound to the transcript so far and `frame(op, label, data)`.
+    fn squeeze(&self, op: u8, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+        let mut s = self.state.clone();
+        framing::write_frame(|b| s.write(b), op, label.as_bytes(), data);
+        default_hasher_expand(&s, out_len)
+    }
 }
 impl Absorb for HashOracle {
     fn absorb_bytes(&mut self, label: &'static str, bytes: &[u8]) {
-        self.buf.extend_from_slice(self.domain);
-        self.buf.extend_from_slice(label.as_bytes());
-        self.buf.extend_from_slice(bytes);
+        framing::write_frame(|b| self.state.write(b), framing::op::ABSORB, label.as_bytes(), bytes);
     }
 }
 impl Oracle for HashOracle {
     fn challenge<C: Challenge + CanonicalEncode>(&mut self, label: &'static str) -> Result<C> {
-        let mut material = Vec::with_capacity(self.buf.len() + label.len() + self.domain.len());
-        material.extend_from_slice(self.domain);
-        material.extend_from_slice(label.as_bytes());
-        material.extend_from_slice(&self.buf);
-        Ok(C::from_oracle_bytes(label, &material))
+        let out = self.squeeze(framing::op::CHALLENGE, label, &(C::BYTES as u64).to_le_bytes(), C::BYTES);
+        Ok(C::from_oracle_bytes(label, &out))
     }
 }
 
+/// `out_len` bytes from a finished-input hasher in counter mode: block_i = hash(state, out_len, i).
+/// Demo quality only, like the rest of `HashOracle`.
+fn default_hasher_expand(state: &DefaultHasher, out_len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(out_len + 8);
+    let mut ctr: u64 = 0;
+    while out.len() < out_len {
+        let mut s = state.clone();
+        s.write_u64(out_len as u64);
+        s.write_u64(ctr);
+        out.extend_from_slice(&s.finish().to_le_bytes());
+        ctr += 1;
+    }
+    out.truncate(out_len);
+    out
+}
+
 
 impl RandomOracle for HashOracle {
     fn H_full(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
-        use core::hash::{Hash, Hasher};
-        // domain-separated material: domain || label || transcript_buf || data
-        let mut material =
-            Vec::with_capacity(self.domain.len() + label.len() + self.buf.len() + data.len());
-        material.extend_from_slice(self.domain);
-        material.extend_from_slice(label.as_bytes());
-        material.extend_from_slice(&self.buf);
-        material.extend_from_slice(data);
-
-        // Expand to 32 bytes deterministically (same spirit as your Challenge impl)
-        let mut s = std::collections::hash_map::DefaultHasher::new();
-        material.hash(&mut s);
-        let mut seed = s.finish();
-
-        let mut out = Vec::with_capacity(32);
-        for _ in 0..4 {
-            out.extend_from_slice(&seed.to_le_bytes());
-            seed = seed.rotate_left(17) ^ 0x9E37_79B9_7F4A_7C15u64;
-        }
-        out
+        self.squeeze(framing::op::H_FULL, label, data, 32)
     }
 
     fn H(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
-        // For our purposes Hb can reuse the same compression and be truncated later.
-        self.H_full(label, data)
+        self.squeeze(framing::op::H, label, data, 32)
     }
 }
 
@@ -171,7 +289,7 @@ impl RandomOracle for HashOracle {
 // ---- FS proof recording (works with HashOracle) ----
 
 /// A single recorded transcript event (label + bytes) for FS proof emission.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RecEvent {
     Absorb { label: &'static str, bytes: Vec<u8> },
     Challenge { label: &'static str, bytes: Vec<u8> },
@@ -270,9 +388,30 @@ impl<const PENDING: u128, O: Oracle> Transcript<PENDING, O> {
         Transcript { oracle: self.oracle }
     }
 
+    /// `absorb` with the remaining obligations written out, for code generic over the oracle or
+    /// the message, where `{ PENDING & !MASK }` cannot be evaluated. REST = PENDING & !OBLIG_MASK
+    /// is checked when the call is monomorphized.
+    pub fn absorb_into<const REST: u128, M: Bindable>(
+        mut self,
+        label: &'static str,
+        m: &M,
+    ) -> Transcript<REST, O> {
+        let () = Rest::<PENDING, REST, M>::CHECK;
+        m.bind(&mut self.oracle);
+        self.oracle.absorb_bytes(label, &[]);
+        Transcript { oracle: self.oracle }
+    }
+
     pub fn oracle_mut(&mut self) -> &mut O { &mut self.oracle }
 }
 
+/// Post-monomorphization check for `absorb_into` (an associated const, since `generic_const_exprs`
+/// rejects inline `const` blocks with control flow).
+struct Rest<const PENDING: u128, const REST: u128, M>(core::marker::PhantomData<M>);
+impl<const PENDING: u128, const REST: u128, M: Bindable> Rest<PENDING, REST, M> {
+    const CHECK: () = assert!(REST == PENDING & !M::OBLIG_MASK, "absorb_into: REST must be PENDING & !OBLIG_MASK");
+}
+
 // Challenge is only available when PENDING == 0
 impl<O: Oracle> Transcript<0, O> {
     pub fn challenge<C: Challenge + CanonicalEncode>(
@@ -282,6 +421,20 @@ impl<O: Oracle> Transcript<0, O> {
         let c = self.oracle.challenge::<C>(label)?;
         Ok((c, self))
     }
+
+    /// Move on to a round with obligations NEW once every obligation of this one is met: the
+    /// checked `retag`. NEW ≠ 0 is checked when the call is monomorphized, so the next challenge
+    /// cannot be drawn before that round's messages are absorbed.
+    pub fn next_round<const NEW: u128>(self) -> Transcript<NEW, O> {
+        let () = NextRound::<NEW>::CHECK;
+        Transcript { oracle: self.oracle }
+    }
+}
+
+/// Post-monomorphization check for `next_round`.
+struct NextRound<const NEW: u128>;
+impl<const NEW: u128> NextRound<NEW> {
+    const CHECK: () = assert!(NEW != 0, "next_round: the next round must have obligations");
 }
 
 // Helper
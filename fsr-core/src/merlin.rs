@@ -0,0 +1,211 @@
+//! Merlin-compatible transcript oracle (feature = "merlin").
+//!
+//! `MerlinOracle` reproduces Merlin's STROBE-128 transcript byte for byte:
+//! `absorb_bytes(label, bytes)` is Merlin's `append_message(label, bytes)` and
+//! `challenge::<C>(label)` is `challenge_bytes(label, [0; C::BYTES])`. A proof built on
+//! `Transcript<PENDING, MerlinOracle>` therefore derives the same challenges as a Merlin-based
+//! verifier that appends the same messages, and vice versa.
+//!
+//! Framing is Merlin's own rather than `framing`'s: every `append_message` is a STROBE meta-AD of
+//! the label and the u32 message length followed by an AD of the message, which is already
+//! injective.
+//!
+//! Note that `Transcript::absorb` ends every typed message with an empty `append_message(label, b"")`
+//! marker; a hand-written Merlin peer has to append it too.
+//!
+//! The STROBE core is implemented here on top of `keccak::f1600` (only meta-AD, AD and PRF are
+//! needed), so `fsr-core` keeps `#![forbid(unsafe_code)]`.
+
+use crate::runtime::{RandomOracle, TranscriptRuntime};
+use crate::{Absorb, CanonicalEncode, Challenge, Oracle, Result};
+
+/// Merlin's STROBE protocol label.
+const MERLIN_PROTOCOL_LABEL: &[u8] = b"Merlin v1.0";
+
+// STROBE-128: R = 200 - 2*16 - 2.
+const STROBE_R: u8 = 166;
+
+const FLAG_I: u8 = 1;
+const FLAG_A: u8 = 1 << 1;
+const FLAG_C: u8 = 1 << 2;
+const FLAG_M: u8 = 1 << 4;
+
+// Output length of `H_full` / `H`.
+const RO_BYTES: usize = 32;
+
+/// Minimal STROBE-128 (meta-AD, AD and PRF), state-compatible with Merlin's.
+#[derive(Clone)]
+pub struct Strobe128 {
+    state: [u64; 25],
+    pos: u8,
+    pos_begin: u8,
+    cur_flags: u8,
+}
+
+impl Strobe128 {
+    pub fn new(protocol_label: &[u8]) -> Self {
+        let mut s = Strobe128 { state: [0u64; 25], pos: 0, pos_begin: 0, cur_flags: 0 };
+        for (i, b) in [1, STROBE_R + 2, 1, 0, 1, 96].iter().chain(b"STROBEv1.0.2").enumerate() {
+            s.set_byte(i, *b);
+        }
+        keccak::f1600(&mut s.state);
+        s.meta_ad(protocol_label, false);
+        s
+    }
+
+    pub fn meta_ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_M | FLAG_A, more);
+        self.absorb(data);
+    }
+
+    pub fn ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_A, more);
+        self.absorb(data);
+    }
+
+    pub fn prf(&mut self, out: &mut [u8], more: bool) {
+        self.begin_op(FLAG_I | FLAG_A | FLAG_C, more);
+        self.squeeze(out);
+    }
+
+    #[inline]
+    fn byte(&self, i: usize) -> u8 {
+        (self.state[i / 8] >> (8 * (i % 8))) as u8
+    }
+
+    #[inline]
+    fn xor_byte(&mut self, i: usize, b: u8) {
+        self.state[i / 8] ^= (b as u64) << (8 * (i % 8));
+    }
+
+    #[inline]
+    fn set_byte(&mut self, i: usize, b: u8) {
+        let old = self.byte(i);
+        self.xor_byte(i, old ^ b);
+    }
+
+    fn run_f(&mut self) {
+        self.xor_byte(self.pos as usize, self.pos_begin);
+        self.xor_byte(self.pos as usize + 1, 0x04);
+        self.xor_byte(STROBE_R as usize + 1, 0x80);
+        keccak::f1600(&mut self.state);
+        self.pos = 0;
+        self.pos_begin = 0;
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        for &b in data {
+            self.xor_byte(self.pos as usize, b);
+            self.pos += 1;
+            if self.pos == STROBE_R {
+                self.run_f();
+            }
+        }
+    }
+
+    fn squeeze(&mut self, out: &mut [u8]) {
+        for o in out.iter_mut() {
+            *o = self.byte(self.pos as usize);
+            self.set_byte(self.pos as usize, 0);
+            self.pos += 1;
+            if self.pos == STROBE_R {
+                self.run_f();
+            }
+        }
+    }
+
+    fn begin_op(&mut self, flags: u8, more: bool) {
+        if more {
+            assert_eq!(self.cur_flags, flags, "STROBE: continued op with different flags");
+            return;
+        }
+        let old_begin = self.pos_begin;
+        self.pos_begin = self.pos + 1;
+        self.cur_flags = flags;
+        self.absorb(&[old_begin, flags]);
+        // C (and K) force a permutation before the operation's data.
+        if flags & FLAG_C != 0 && self.pos != 0 {
+            self.run_f();
+        }
+    }
+}
+
+/// Merlin transcript as an `fsr-core` oracle.
+#[derive(Clone)]
+pub struct MerlinOracle {
+    strobe: Strobe128,
+}
+
+impl MerlinOracle {
+    /// Same as `merlin::Transcript::new(label)`.
+    pub fn new(label: &'static [u8]) -> Self {
+        let mut t = Self { strobe: Strobe128::new(MERLIN_PROTOCOL_LABEL) };
+        t.append_message(b"dom-sep", label);
+        t
+    }
+
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&(message.len() as u32).to_le_bytes(), true);
+        self.strobe.ad(message, false);
+    }
+
+    pub fn append_u64(&mut self, label: &[u8], x: u64) {
+        self.append_message(label, &x.to_le_bytes());
+    }
+
+    pub fn challenge_bytes(&mut self, label: &[u8], dest: &mut [u8]) {
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&(dest.len() as u32).to_le_bytes(), true);
+        self.strobe.prf(dest, false);
+    }
+}
+
+impl TranscriptRuntime for MerlinOracle {
+    fn absorb(&mut self, label: &'static str, bytes: &[u8]) {
+        self.append_message(label.as_bytes(), bytes);
+    }
+}
+
+impl Absorb for MerlinOracle {
+    fn absorb_bytes(&mut self, label: &'static str, bytes: &[u8]) {
+        self.append_message(label.as_bytes(), bytes);
+    }
+}
+
+impl Oracle for MerlinOracle {
+    fn challenge<C: Challenge + CanonicalEncode>(&mut self, label: &'static str) -> Result<C> {
+        let mut out = vec![0u8; C::BYTES];
+        self.challenge_bytes(label.as_bytes(), &mut out);
+        Ok(C::from_oracle_bytes(label, &out))
+    }
+}
+
+/// `H_full` / `H` run on a fork of the transcript, so `FSOracle` / `FischlinOracle` can use
+/// Merlin as their hash without advancing it. These outputs are not part of Merlin's API.
+impl RandomOracle for MerlinOracle {
+    fn H_full(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+        let mut fork = self.clone();
+        fork.append_message(b"fsr/H_full", data);
+        let mut out = vec![0u8; RO_BYTES];
+        fork.challenge_bytes(label.as_bytes(), &mut out);
+        out
+    }
+
+    fn H(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+        let mut fork = self.clone();
+        fork.append_message(b"fsr/H_b", data);
+        let mut out = vec![0u8; RO_BYTES];
+        fork.challenge_bytes(label.as_bytes(), &mut out);
+        out
+    }
+
+    // `challenge_bytes` already binds the output length.
+    fn H_xof(&mut self, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+        let mut fork = self.clone();
+        fork.append_message(b"fsr/H_xof", data);
+        let mut out = vec![0u8; out_len];
+        fork.challenge_bytes(label.as_bytes(), &mut out);
+        out
+    }
+}
@@ -0,0 +1,175 @@
+//! Poseidon random oracle over a prime field (feature = "poseidon").
+//!
+//! `PoseidonOracle<F>` is an algebraic transcript meant to be re-verified inside a SNARK over `F`.
+//! It implements `TranscriptRuntime`, `Absorb`, `Oracle` and `RandomOracle`, so `Transcript`,
+//! `FSOracle` and `FischlinOracle` use it exactly as they use the byte-oriented hashes.
+//!
+//! Bytes are packed into field elements canonically: `len(bytes)` as one element, followed by
+//! little-endian chunks of `(F::MODULUS_BIT_SIZE - 1) / 8` bytes (each chunk is strictly below the
+//! modulus, so packing is injective). Every operation is framed as `op, label, data` with the op
+//! codes of `framing` and each of `label` / `data` packed that way; `absorb_field` frames native
+//! elements as `op, label, count, elems`.
+//!
+//! Challenges are native field elements (`challenge_field`). Byte challenges (`Oracle::challenge`,
+//! `H_full`, `H`) take the low `BYTES_PER_ELEM` bytes of each squeezed element, leaving at least
+//! 128 bits of the element unused so the bytes are statistically uniform.
+//!
+//! Parameter sets (width 3, rate 2, x^5 S-box, 8 full / 57 partial rounds, Grain LFSR round
+//! constants and MDS) are provided for the BN254 and BLS12-381 scalar fields.
+
+use std::sync::OnceLock;
+
+use ark_crypto_primitives::sponge::poseidon::{find_poseidon_ark_and_mds, PoseidonConfig, PoseidonSponge};
+use ark_crypto_primitives::sponge::{Absorb as SpongeAbsorb, CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::framing::op;
+use crate::runtime::{RandomOracle, TranscriptRuntime};
+use crate::{Absorb, CanonicalEncode, Challenge, Oracle, Result};
+
+// Output length of `H_full` / `H`.
+const RO_BYTES: usize = 32;
+
+const FULL_ROUNDS: u64 = 8;
+const PARTIAL_ROUNDS: u64 = 57;
+const ALPHA: u64 = 5;
+const RATE: usize = 2;
+const CAPACITY: usize = 1;
+
+/// Width-3 Poseidon parameters for `F`, generated with the Grain LFSR of the Poseidon paper.
+pub fn poseidon_config<F: PrimeField>() -> PoseidonConfig<F> {
+    let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+        F::MODULUS_BIT_SIZE as u64, RATE, FULL_ROUNDS, PARTIAL_ROUNDS, 0,
+    );
+    PoseidonConfig::new(FULL_ROUNDS as usize, PARTIAL_ROUNDS as usize, ALPHA, mds, ark, RATE, CAPACITY)
+}
+
+/// Parameter set for the BN254 scalar field (computed once).
+pub fn bn254_config() -> &'static PoseidonConfig<ark_bn254::Fr> {
+    static CONFIG: OnceLock<PoseidonConfig<ark_bn254::Fr>> = OnceLock::new();
+    CONFIG.get_or_init(poseidon_config)
+}
+
+/// Parameter set for the BLS12-381 scalar field (computed once).
+pub fn bls12_381_config() -> &'static PoseidonConfig<ark_bls12_381::Fr> {
+    static CONFIG: OnceLock<PoseidonConfig<ark_bls12_381::Fr>> = OnceLock::new();
+    CONFIG.get_or_init(poseidon_config)
+}
+
+/// Poseidon transcript over the field `F`.
+#[derive(Clone)]
+pub struct PoseidonOracle<F: PrimeField + SpongeAbsorb> {
+    sponge: PoseidonSponge<F>,
+}
+
+pub type PoseidonBn254 = PoseidonOracle<ark_bn254::Fr>;
+pub type PoseidonBls12_381 = PoseidonOracle<ark_bls12_381::Fr>;
+
+impl PoseidonOracle<ark_bn254::Fr> {
+    pub fn bn254(domain: &'static [u8]) -> Self { Self::new(bn254_config(), domain) }
+}
+
+impl PoseidonOracle<ark_bls12_381::Fr> {
+    pub fn bls12_381(domain: &'static [u8]) -> Self { Self::new(bls12_381_config(), domain) }
+}
+
+impl<F: PrimeField + SpongeAbsorb> PoseidonOracle<F> {
+    /// Bytes per packed input chunk (strictly below the modulus).
+    pub const CHUNK_BYTES: usize = (F::MODULUS_BIT_SIZE as usize - 1) / 8;
+    /// Bytes taken from each squeezed element for byte-valued outputs.
+    pub const BYTES_PER_ELEM: usize = (F::MODULUS_BIT_SIZE as usize - 128) / 8;
+
+    pub fn new(config: &PoseidonConfig<F>, domain: &'static [u8]) -> Self {
+        let mut s = Self { sponge: PoseidonSponge::new(config) };
+        Self::frame(&mut s.sponge, op::DOMAIN, "domain", domain);
+        s
+    }
+
+    /// Canonical, injective byte → field-element packing.
+    pub fn pack_bytes(bytes: &[u8], out: &mut Vec<F>) {
+        out.push(F::from(bytes.len() as u64));
+        for chunk in bytes.chunks(Self::CHUNK_BYTES) {
+            out.push(F::from_le_bytes_mod_order(chunk));
+        }
+    }
+
+    fn frame(sponge: &mut PoseidonSponge<F>, op: u8, label: &str, data: &[u8]) {
+        let mut elems = vec![F::from(op)];
+        Self::pack_bytes(label.as_bytes(), &mut elems);
+        Self::pack_bytes(data, &mut elems);
+        sponge.absorb(&elems);
+    }
+
+    /// Absorb field elements directly (no byte packing), e.g. a statement that is already native.
+    pub fn absorb_field(&mut self, label: &'static str, elems: &[F]) {
+        let mut head = vec![F::from(op::ABSORB_FIELD)];
+        Self::pack_bytes(label.as_bytes(), &mut head);
+        head.push(F::from(elems.len() as u64));
+        self.sponge.absorb(&head);
+        self.sponge.absorb(&elems);
+    }
+
+    /// A native challenge: one squeezed field element bound to the transcript so far.
+    pub fn challenge_field(&mut self, label: &'static str) -> F {
+        Self::frame(&mut self.sponge, op::CHALLENGE, label, &[]);
+        self.sponge.squeeze_native_field_elements(1)[0]
+    }
+
+    fn squeeze_bytes(sponge: &mut PoseidonSponge<F>, out_len: usize) -> Vec<u8> {
+        let n = out_len.div_ceil(Self::BYTES_PER_ELEM);
+        let mut out = Vec::with_capacity(n * Self::BYTES_PER_ELEM);
+        for e in sponge.squeeze_native_field_elements(n) {
+            out.extend_from_slice(&e.into_bigint().to_bytes_le()[..Self::BYTES_PER_ELEM]);
+        }
+        out.truncate(out_len);
+        out
+    }
+
+    /// `out_len` uniform challenge bytes bound to the transcript so far.
+    pub fn challenge_bytes(&mut self, label: &'static str, out_len: usize) -> Vec<u8> {
+        Self::frame(&mut self.sponge, op::CHALLENGE, label, &(out_len as u64).to_le_bytes());
+        Self::squeeze_bytes(&mut self.sponge, out_len)
+    }
+
+    /// Hash of `(transcript so far, label, data)` on a fork; does not advance the transcript.
+    fn fork_hash(&self, op: u8, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+        let mut s = self.sponge.clone();
+        Self::frame(&mut s, op, label, data);
+        Self::squeeze_bytes(&mut s, out_len)
+    }
+}
+
+impl<F: PrimeField + SpongeAbsorb> TranscriptRuntime for PoseidonOracle<F> {
+    fn absorb(&mut self, label: &'static str, bytes: &[u8]) {
+        Self::frame(&mut self.sponge, op::ABSORB, label, bytes);
+    }
+}
+
+impl<F: PrimeField + SpongeAbsorb> Absorb for PoseidonOracle<F> {
+    fn absorb_bytes(&mut self, label: &'static str, bytes: &[u8]) {
+        Self::frame(&mut self.sponge, op::ABSORB, label, bytes);
+    }
+}
+
+impl<F: PrimeField + SpongeAbsorb> Oracle for PoseidonOracle<F> {
+    fn challenge<C: Challenge + CanonicalEncode>(&mut self, label: &'static str) -> Result<C> {
+        let out = self.challenge_bytes(label, C::BYTES);
+        Ok(C::from_oracle_bytes(label, &out))
+    }
+}
+
+impl<F: PrimeField + SpongeAbsorb> RandomOracle for PoseidonOracle<F> {
+    fn H_full(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+        self.fork_hash(op::H_FULL, label, data, RO_BYTES)
+    }
+
+    fn H(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+        self.fork_hash(op::H, label, data, RO_BYTES)
+    }
+
+    fn H_xof(&mut self, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+        let mut input = (out_len as u64).to_le_bytes().to_vec();
+        input.extend_from_slice(data);
+        self.fork_hash(op::H_XOF, label, &input, out_len)
+    }
+}
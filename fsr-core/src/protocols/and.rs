@@ -0,0 +1,151 @@
+//! AND composition: prove both statements with one shared challenge.
+//!
+//! `And<P1, P2>` runs both protocols side by side, answers the same e in both, and accepts iff
+//! both branches accept; `AndN<P>` does the same for any number of instances of one protocol.
+//! Branch commitments are bound under the scopes `and.0`, `and.1`, ... (see `sigma::Labels`), so
+//! nested compositions get distinct label paths. `simulate` and `extract` compose branch-wise:
+//! transcripts of the composition are transcripts of every branch with the same challenges.
+//! Compositions of `LinearSigma`s over one group are `LinearSigma`s (all branch equations).
+
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+
+use crate::batch::{Equation, LinearSigma};
+use crate::sigma::{Binder, Labels, SigmaProtocol};
+
+pub struct And<P1, P2>(PhantomData<(P1, P2)>);
+
+impl<P1, P2> SigmaProtocol for And<P1, P2>
+where
+    P1: SigmaProtocol,
+    P2: SigmaProtocol<Challenge = P1::Challenge>,
+{
+    type Statement = (P1::Statement, P2::Statement);
+    type Witness = (P1::Witness, P2::Witness);
+    type Commitment = (P1::Commitment, P2::Commitment);
+    type Challenge = P1::Challenge;
+    type Response = (P1::Response, P2::Response);
+    type State = (P1::State, P2::State);
+
+    const SPECIAL_SOUNDNESS: usize = max(P1::SPECIAL_SOUNDNESS, P2::SPECIAL_SOUNDNESS);
+
+    fn commit<R: RngCore + ?Sized>(x: &Self::Statement, w: &Self::Witness, rng: &mut R) -> (Self::Commitment, Self::State) {
+        let (a1, s1) = P1::commit(&x.0, &w.0, rng);
+        let (a2, s2) = P2::commit(&x.1, &w.1, rng);
+        ((a1, a2), (s1, s2))
+    }
+
+    fn respond(x: &Self::Statement, w: &Self::Witness, state: &Self::State, e: &Self::Challenge) -> Self::Response {
+        (P1::respond(&x.0, &w.0, &state.0, e), P2::respond(&x.1, &w.1, &state.1, e))
+    }
+
+    fn verify(x: &Self::Statement, a: &Self::Commitment, e: &Self::Challenge, z: &Self::Response) -> bool {
+        P1::verify(&x.0, &a.0, e, &z.0) && P2::verify(&x.1, &a.1, e, &z.1)
+    }
+
+    fn simulate<R: RngCore + ?Sized>(x: &Self::Statement, e: &Self::Challenge, rng: &mut R) -> (Self::Commitment, Self::Response) {
+        let (a1, z1) = P1::simulate(&x.0, e, rng);
+        let (a2, z2) = P2::simulate(&x.1, e, rng);
+        ((a1, a2), (z1, z2))
+    }
+
+    fn extract(x: &Self::Statement, a: &Self::Commitment, transcripts: &[(Self::Challenge, Self::Response)]) -> Option<Self::Witness> {
+        if transcripts.len() < Self::SPECIAL_SOUNDNESS { return None; }
+        let t1: Vec<_> = transcripts[..P1::SPECIAL_SOUNDNESS].iter().map(|(e, z)| (*e, z.0.clone())).collect();
+        let t2: Vec<_> = transcripts[..P2::SPECIAL_SOUNDNESS].iter().map(|(e, z)| (*e, z.1.clone())).collect();
+        Some((P1::extract(&x.0, &a.0, &t1)?, P2::extract(&x.1, &a.1, &t2)?))
+    }
+
+    fn require(x: &Self::Statement, labels: &mut Labels) {
+        labels.scope("and.0", |l| P1::require(&x.0, l));
+        labels.scope("and.1", |l| P2::require(&x.1, l));
+    }
+
+    fn bind(x: &Self::Statement, a: &Self::Commitment, binder: &mut Binder<'_>) {
+        binder.scope("and.0", |b| P1::bind(&x.0, &a.0, b));
+        binder.scope("and.1", |b| P2::bind(&x.1, &a.1, b));
+    }
+}
+
+impl<P1, P2> LinearSigma for And<P1, P2>
+where
+    P1: LinearSigma,
+    P2: LinearSigma<Group = P1::Group>,
+{
+    type Group = P1::Group;
+
+    fn equations(x: &Self::Statement, a: &Self::Commitment, e: &Self::Challenge, z: &Self::Response, out: &mut Vec<Equation<P1::Group>>) -> bool {
+        P1::equations(&x.0, &a.0, e, &z.0, out) && P2::equations(&x.1, &a.1, e, &z.1, out)
+    }
+}
+
+/// AND of `x.len()` instances of `P`; statements, witnesses, commitments and responses are
+/// vectors of equal length, and branch i is bound under `and.{i}`.
+pub struct AndN<P>(PhantomData<P>);
+
+impl<P: SigmaProtocol> SigmaProtocol for AndN<P> {
+    type Statement = Vec<P::Statement>;
+    type Witness = Vec<P::Witness>;
+    type Commitment = Vec<P::Commitment>;
+    type Challenge = P::Challenge;
+    type Response = Vec<P::Response>;
+    type State = Vec<P::State>;
+
+    const SPECIAL_SOUNDNESS: usize = P::SPECIAL_SOUNDNESS;
+
+    fn commit<R: RngCore + ?Sized>(x: &Self::Statement, w: &Self::Witness, rng: &mut R) -> (Self::Commitment, Self::State) {
+        assert_eq!(x.len(), w.len(), "AndN: one witness per statement");
+        x.iter().zip(w).map(|(x, w)| P::commit(x, w, rng)).unzip()
+    }
+
+    fn respond(x: &Self::Statement, w: &Self::Witness, state: &Self::State, e: &Self::Challenge) -> Self::Response {
+        x.iter().zip(w).zip(state).map(|((x, w), s)| P::respond(x, w, s, e)).collect()
+    }
+
+    fn verify(x: &Self::Statement, a: &Self::Commitment, e: &Self::Challenge, z: &Self::Response) -> bool {
+        a.len() == x.len() && z.len() == x.len()
+            && x.iter().zip(a).zip(z).all(|((x, a), z)| P::verify(x, a, e, z))
+    }
+
+    fn simulate<R: RngCore + ?Sized>(x: &Self::Statement, e: &Self::Challenge, rng: &mut R) -> (Self::Commitment, Self::Response) {
+        x.iter().map(|x| P::simulate(x, e, rng)).unzip()
+    }
+
+    fn extract(x: &Self::Statement, a: &Self::Commitment, transcripts: &[(Self::Challenge, Self::Response)]) -> Option<Self::Witness> {
+        if a.len() != x.len() || transcripts.len() < P::SPECIAL_SOUNDNESS { return None; }
+        let transcripts = &transcripts[..P::SPECIAL_SOUNDNESS];
+        if transcripts.iter().any(|(_, z)| z.len() != x.len()) { return None; }
+        (0..x.len())
+            .map(|i| {
+                let ti: Vec<_> = transcripts.iter().map(|(e, z)| (*e, z[i].clone())).collect();
+                P::extract(&x[i], &a[i], &ti)
+            })
+            .collect()
+    }
+
+    fn require(x: &Self::Statement, labels: &mut Labels) {
+        for (i, x) in x.iter().enumerate() {
+            labels.scope(&format!("and.{i}"), |l| P::require(x, l));
+        }
+    }
+
+    fn bind(x: &Self::Statement, a: &Self::Commitment, binder: &mut Binder<'_>) {
+        for (i, (x, a)) in x.iter().zip(a).enumerate() {
+            binder.scope(&format!("and.{i}"), |b| P::bind(x, a, b));
+        }
+    }
+}
+
+impl<P: LinearSigma> LinearSigma for AndN<P> {
+    type Group = P::Group;
+
+    fn equations(x: &Self::Statement, a: &Self::Commitment, e: &Self::Challenge, z: &Self::Response, out: &mut Vec<Equation<P::Group>>) -> bool {
+        a.len() == x.len() && z.len() == x.len()
+            && x.iter().zip(a).zip(z).all(|((x, a), z)| P::equations(x, a, e, z, out))
+    }
+}
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b { a } else { b }
+}
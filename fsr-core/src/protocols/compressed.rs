@@ -0,0 +1,236 @@
+//! Compressed Σ-protocols for linear forms (Attema–Cramer): knowledge of x ∈ F^n with
+//! P = Σ g_i·x_i and L(x) = Σ l_i·x_i = y, in 2⌈log2 n⌉ + 1 group elements and two scalars.
+//!
+//! Π0 is the basic Σ-protocol: A = Σ g_i·r_i, t = L(r), challenge c, z = r + c·x, and the
+//! verifier would check Σ g_i·z_i = A + c·P and L(z) = c·y + t. Instead of sending z, a second
+//! challenge ξ merges both checks into one vector commitment, h_i = g_i + ξ·l_i·k and
+//! Q = A + c·P + ξ·(c·y + t)·k, and z is proven to be Q's opening by folding (Bulletproofs
+//! style): each round sends A_j = Σ z_L·h_R and B_j = Σ z_R·h_L, draws c_j, and continues with
+//! z' = z_L + c_j·z_R, h' = c_j·h_L + h_R, Q' = A_j + c_j·Q + c_j²·B_j, until one entry is
+//! left and z is sent. k must have no known discrete log to the g_i (ξ is drawn after A, so A
+//! cannot carry a k-component that offsets L). Vectors are padded to a power of two with
+//! identity generators and zero coefficients.
+//!
+//! As in `dleq` and `linear`, the messages are `FsrBindable`: `Statement` and `Commit` make up
+//! `Round`, and every folding round is a `FoldRound` whose `Fold` obligation must be absorbed
+//! before its challenge. The verifier recomputes the folded generator as one `PrimeGroup::msm`
+//! over the g_i together with Q, so verification is a single multi-scalar multiplication.
+
+use rand_core::RngCore;
+
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::{CanonicalDecode, CanonicalEncode, Direction, FsrBindable, Message, Oracle, Result, Transcript};
+
+const STATEMENT: u128 = 1 << 0;
+const COMMIT: u128 = 1 << 1;
+const FOLD: u128 = 1 << 2;
+const ROUND: u128 = STATEMENT | COMMIT;
+
+/// The Π0 round: statement and commitment pending.
+pub type Round<O> = Transcript<ROUND, O>;
+/// One folding round: its `Fold` pending.
+pub type FoldRound<O> = Transcript<FOLD, O>;
+
+/// Σ g_i·x_i = p and Σ l_i·x_i = y, with k the generator the linear form is folded onto.
+#[derive(Clone, Debug, PartialEq, Eq, FsrBindable)]
+#[bind(prefix = "Compressed.Statement", core = "crate")]
+pub struct Statement<G: PrimeGroup> {
+    #[bind(ob = 0)] pub g: Vec<G>,
+    pub k: G,
+    pub l: Vec<G::Scalar>,
+    pub p: G,
+    pub y: G::Scalar,
+}
+impl<G: PrimeGroup> Message for Statement<G> {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Compressed.Statement";
+}
+
+impl<G: PrimeGroup> Statement<G> {
+    /// p = Σ g_i·x_i and y = L(x), for a prover holding x. A Pedersen vector commitment with
+    /// blinding γ is x = (v, γ) over (g, h), with l_h = 0.
+    pub fn image(g: Vec<G>, k: G, l: Vec<G::Scalar>, x: &[G::Scalar]) -> Self {
+        assert!(!g.is_empty() && g.len() == l.len() && g.len() == x.len(), "compressed: g, l and x need one entry each");
+        let p = G::msm(&pairs(x, &g));
+        let y = inner(&l, x);
+        Statement { g, k, l, p, y }
+    }
+
+    /// The number of folding rounds, ⌈log2 n⌉.
+    pub fn rounds(&self) -> usize { self.g.len().next_power_of_two().trailing_zeros() as usize }
+
+    fn is_valid(&self) -> bool { !self.g.is_empty() && self.g.len() == self.l.len() }
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Statement<G> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.g.encode(out);
+        self.k.encode(out);
+        self.l.encode(out);
+        self.p.encode(out);
+        self.y.encode(out);
+    }
+}
+
+/// Π0's first message: A = Σ g_i·r_i, t = L(r).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FsrBindable)]
+#[bind(prefix = "Compressed.Commit", core = "crate")]
+pub struct Commit<G: PrimeGroup> {
+    #[bind(ob = 1)] pub a: G,
+    pub t: G::Scalar,
+}
+impl<G: PrimeGroup> Message for Commit<G> {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Compressed.Commit";
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Commit<G> {
+    fn encode(&self, out: &mut Vec<u8>) { (self.a, self.t).encode(out); }
+}
+impl<G: PrimeGroup> CanonicalDecode for Commit<G> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let (a, t) = <(G, G::Scalar)>::decode(input)?;
+        Some(Commit { a, t })
+    }
+}
+
+/// One folding round's cross terms: a = Σ z_L·h_R, b = Σ z_R·h_L.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FsrBindable)]
+#[bind(prefix = "Compressed.Fold", core = "crate")]
+pub struct Fold<G: PrimeGroup> {
+    #[bind(ob = 2)] pub a: G,
+    pub b: G,
+}
+impl<G: PrimeGroup> Message for Fold<G> {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Compressed.Fold";
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Fold<G> {
+    fn encode(&self, out: &mut Vec<u8>) { (self.a, self.b).encode(out); }
+}
+impl<G: PrimeGroup> CanonicalDecode for Fold<G> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let (a, b) = <(G, G)>::decode(input)?;
+        Some(Fold { a, b })
+    }
+}
+
+/// The Π0 commitment, one `Fold` per round and the last entry of z: `commit || u32 count ||
+/// folds || z`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof<G: PrimeGroup> {
+    pub commit: Commit<G>,
+    pub folds: Vec<Fold<G>>,
+    pub z: G::Scalar,
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Proof<G> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.commit.encode(out);
+        self.folds.encode(out);
+        self.z.encode(out);
+    }
+}
+impl<G: PrimeGroup> CanonicalDecode for Proof<G> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let mut rest = *input;
+        let commit = Commit::decode(&mut rest)?;
+        let folds = Vec::decode(&mut rest)?;
+        let z = G::Scalar::decode(&mut rest)?;
+        *input = rest;
+        Some(Proof { commit, folds, z })
+    }
+}
+
+/// Π0 on the typed transcript: (c, ξ) after absorbing `st` and `commit`.
+fn challenges<G: PrimeGroup, O: Oracle>(tr: Round<O>, st: &Statement<G>, commit: &Commit<G>) -> Result<(G::Scalar, G::Scalar, Transcript<0, O>)> {
+    let tr = tr.absorb_into::<COMMIT, _>(Statement::<G>::LABEL, st);
+    let tr = tr.absorb_into::<0, _>(Commit::<G>::LABEL, commit);
+    let (c, tr) = tr.challenge::<G::Scalar>("Compressed.c")?;
+    let (xi, tr) = tr.challenge::<G::Scalar>("Compressed.xi")?;
+    Ok((c, xi, tr))
+}
+
+/// One folding round: absorb `fold` (the round's obligation), then draw c_j. `next_round` only
+/// leaves a finished round, and c_j is only drawn once `fold` has cleared FOLD.
+fn fold_challenge<G: PrimeGroup, O: Oracle>(tr: Transcript<0, O>, fold: &Fold<G>) -> Result<(G::Scalar, Transcript<0, O>)> {
+    let tr: FoldRound<O> = tr.next_round();
+    let tr = tr.absorb_into::<0, _>(Fold::<G>::LABEL, fold);
+    tr.challenge::<G::Scalar>("Compressed.fold")
+}
+
+/// FS proof of `st` with witness x on the typed transcript `tr`.
+pub fn prove<G: PrimeGroup, O: Oracle, R: RngCore + ?Sized>(tr: Round<O>, st: &Statement<G>, x: &[G::Scalar], rng: &mut R) -> Result<Proof<G>> {
+    assert!(st.is_valid() && x.len() == st.g.len(), "compressed: need one witness entry per generator");
+    let n = st.g.len();
+    let r: Vec<G::Scalar> = (0..n).map(|_| G::Scalar::random(rng)).collect();
+    let commit = Commit { a: G::msm(&pairs(&r, &st.g)), t: inner(&st.l, &r) };
+    let (c, xi, mut tr) = challenges(tr, st, &commit)?;
+
+    let m = n.next_power_of_two();
+    let mut z: Vec<G::Scalar> = r.iter().zip(x).map(|(r_i, x_i)| *r_i + c * *x_i).collect();
+    z.resize(m, G::Scalar::zero());
+    let mut h: Vec<G> = st.g.iter().zip(&st.l).map(|(g, l)| *g + st.k * (xi * *l)).collect();
+    h.resize(m, G::identity());
+
+    let mut folds = Vec::with_capacity(st.rounds());
+    while z.len() > 1 {
+        let half = z.len() / 2;
+        let (z_l, z_r) = z.split_at(half);
+        let (h_l, h_r) = h.split_at(half);
+        let fold = Fold { a: G::msm(&pairs(z_l, h_r)), b: G::msm(&pairs(z_r, h_l)) };
+        let (c_j, next) = fold_challenge(tr, &fold)?;
+        tr = next;
+        z = z_l.iter().zip(z_r).map(|(l, r)| *l + c_j * *r).collect();
+        h = h_l.iter().zip(h_r).map(|(l, r)| *l * c_j + *r).collect();
+        folds.push(fold);
+    }
+    Ok(Proof { commit, folds, z: z[0] })
+}
+
+pub fn verify<G: PrimeGroup, O: Oracle>(tr: Round<O>, st: &Statement<G>, proof: &Proof<G>) -> bool {
+    if !st.is_valid() || proof.folds.len() != st.rounds() { return false; }
+    let Ok((c, xi, mut tr)) = challenges(tr, st, &proof.commit) else { return false };
+    let mut cs = Vec::with_capacity(proof.folds.len());
+    for fold in &proof.folds {
+        let Ok((c_j, next)) = fold_challenge(tr, fold) else { return false };
+        tr = next;
+        cs.push(c_j);
+    }
+
+    // Q_R = Π c_j·Q_0 + Σ_j (Π_{i>j} c_i)·(A_j + c_j²·B_j), checked against z·Σ s_i·h_i.
+    let mut terms = Vec::with_capacity(st.g.len() + 2 * cs.len() + 4);
+    let mut scale = G::Scalar::one();
+    for (fold, c_j) in proof.folds.iter().zip(&cs).rev() {
+        terms.push((scale, fold.a));
+        terms.push((scale * *c_j * *c_j, fold.b));
+        scale = scale * *c_j;
+    }
+    let Commit { a, t } = proof.commit;
+    terms.push((scale, a));
+    terms.push((scale * c, st.p));
+    let s = fold_coefficients(&cs);
+    let l_s = inner(&s[..st.l.len()], &st.l);
+    terms.push((scale * xi * (c * st.y + t) - proof.z * xi * l_s, st.k));
+    terms.extend(s.iter().zip(&st.g).map(|(s_i, g)| (-proof.z * *s_i, *g)));
+    G::msm(&terms).is_identity()
+}
+
+/// s_i with Σ s_i·h_i the folded generator: the product of the c_j of the rounds in which
+/// entry i is in the left half.
+fn fold_coefficients<F: PrimeField>(cs: &[F]) -> Vec<F> {
+    let mut s = vec![F::one()];
+    for c_j in cs.iter().rev() {
+        s = s.iter().map(|v| *c_j * *v).chain(s.iter().copied()).collect();
+    }
+    s
+}
+
+fn inner<F: PrimeField>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).fold(F::zero(), |acc, (x, y)| acc + *x * *y)
+}
+
+fn pairs<G: PrimeGroup>(s: &[G::Scalar], p: &[G]) -> Vec<(G::Scalar, G)> {
+    s.iter().copied().zip(p.iter().copied()).collect()
+}
@@ -0,0 +1,233 @@
+//! Chaum–Pedersen proofs of discrete-log equality, log_g(a) = log_h(b), and a batched form for
+//! many pairs under one key.
+//!
+//! t_g = r·g, t_h = r·h, z = r + e·x; the verifier checks z·g = t_g + e·a and z·h = t_h + e·b.
+//!
+//! `prove` / `verify` run on the typed `Transcript`: `Statement` and `Commit` are `FsrBindable`
+//! messages carrying the two obligation bits of `Round`, so the challenge only exists once both
+//! are absorbed. Proofs are `FsProof`s (m = the commitment, z = the response). `Dleq<G>` is the
+//! same protocol as a `SigmaProtocol`, which gives `FischlinProof`s through the `sigma` drivers.
+//!
+//! Batching: for pairs (h_i, b_i) under one key a = x·g, `combine` absorbs the whole `Batch` and
+//! draws one weight d_i per pair; a single DLEQ then shows log_g(a) = log_h(b) for
+//! h = Σ d_i·h_i, b = Σ d_i·b_i. If some b_i ≠ x·h_i, the combination holds with probability
+//! 1/|F| over the weights.
+
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+
+use crate::batch::{Equation, LinearSigma};
+use crate::fischlin::FischlinParams;
+use crate::fischlin_proof::FischlinProof;
+use crate::fs_proof::FsProof;
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::runtime::RandomOracle;
+use crate::sigma::{self, decode_exact, encode, SigmaProtocol};
+use crate::{CanonicalDecode, CanonicalEncode, Direction, FsrBindable, Message, Oracle, Result, Transcript};
+
+const STATEMENT: u128 = 1 << 0;
+const COMMIT: u128 = 1 << 1;
+const BATCH: u128 = 1 << 2;
+const ROUND: u128 = STATEMENT | COMMIT;
+
+/// One DLEQ round: statement and commitment pending.
+pub type Round<O> = Transcript<ROUND, O>;
+/// Batch weights: the batch pending.
+pub type BatchRound<O> = Transcript<BATCH, O>;
+
+/// log_g(a) = log_h(b).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FsrBindable)]
+#[bind(prefix = "Dleq.Statement", core = "crate")]
+pub struct Statement<G: PrimeGroup> {
+    #[bind(ob = 0)] pub g: G,
+    pub h: G,
+    pub a: G,
+    pub b: G,
+}
+impl<G: PrimeGroup> Message for Statement<G> {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Dleq.Statement";
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FsrBindable)]
+#[bind(prefix = "Dleq.Commit", core = "crate")]
+pub struct Commit<G: PrimeGroup> {
+    #[bind(ob = 1)] pub t_g: G,
+    pub t_h: G,
+}
+impl<G: PrimeGroup> Message for Commit<G> {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Dleq.Commit";
+}
+
+/// log_g(a) = log_{h_i}(b_i) for every pair.
+#[derive(Clone, Debug, PartialEq, Eq, FsrBindable)]
+#[bind(prefix = "Dleq.Batch", core = "crate")]
+pub struct Batch<G: PrimeGroup> {
+    #[bind(ob = 2)] pub g: G,
+    pub a: G,
+    pub pairs: Vec<(G, G)>,
+}
+impl<G: PrimeGroup> Message for Batch<G> {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Dleq.Batch";
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Statement<G> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        for p in [&self.g, &self.h, &self.a, &self.b] { p.encode(out); }
+    }
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Commit<G> {
+    fn encode(&self, out: &mut Vec<u8>) { (self.t_g, self.t_h).encode(out); }
+}
+impl<G: PrimeGroup> CanonicalDecode for Commit<G> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let (t_g, t_h) = <(G, G)>::decode(input)?;
+        Some(Commit { t_g, t_h })
+    }
+}
+
+pub struct Dleq<G>(PhantomData<G>);
+
+impl<G: PrimeGroup> SigmaProtocol for Dleq<G> {
+    type Statement = Statement<G>;
+    type Witness = G::Scalar;
+    type Commitment = Commit<G>;
+    type Challenge = G::Scalar;
+    type Response = G::Scalar;
+    type State = G::Scalar;
+
+    fn commit<R: RngCore + ?Sized>(st: &Statement<G>, _x: &G::Scalar, rng: &mut R) -> (Commit<G>, G::Scalar) {
+        let r = G::Scalar::random(rng);
+        (Commit { t_g: st.g * r, t_h: st.h * r }, r)
+    }
+
+    fn respond(_st: &Statement<G>, x: &G::Scalar, r: &G::Scalar, e: &G::Scalar) -> G::Scalar {
+        *r + *e * *x
+    }
+
+    fn verify(st: &Statement<G>, c: &Commit<G>, e: &G::Scalar, z: &G::Scalar) -> bool {
+        st.g * *z == c.t_g + st.a * *e && st.h * *z == c.t_h + st.b * *e
+    }
+
+    fn simulate<R: RngCore + ?Sized>(st: &Statement<G>, e: &G::Scalar, rng: &mut R) -> (Commit<G>, G::Scalar) {
+        let z = G::Scalar::random(rng);
+        (Commit { t_g: st.g * z - st.a * *e, t_h: st.h * z - st.b * *e }, z)
+    }
+
+    fn extract(st: &Statement<G>, c: &Commit<G>, transcripts: &[(G::Scalar, G::Scalar)]) -> Option<G::Scalar> {
+        let [(e1, z1), (e2, z2)] = transcripts else { return None };
+        if !Self::verify(st, c, e1, z1) || !Self::verify(st, c, e2, z2) { return None; }
+        Some((*z1 - *z2) * (*e1 - *e2).invert()?)
+    }
+}
+
+impl<G: PrimeGroup> LinearSigma for Dleq<G> {
+    type Group = G;
+
+    fn equations(st: &Statement<G>, c: &Commit<G>, e: &G::Scalar, z: &G::Scalar, out: &mut Vec<Equation<G>>) -> bool {
+        let minus_one = -G::Scalar::one();
+        out.push(Equation { base: G::Scalar::zero(), terms: vec![(*z, st.g), (minus_one, c.t_g), (-*e, st.a)] });
+        out.push(Equation { base: G::Scalar::zero(), terms: vec![(*z, st.h), (minus_one, c.t_h), (-*e, st.b)] });
+        true
+    }
+}
+
+fn challenge<G: PrimeGroup, O: Oracle>(tr: Round<O>, st: &Statement<G>, c: &Commit<G>) -> Result<G::Scalar> {
+    let tr = tr.absorb_into::<COMMIT, _>(Statement::<G>::LABEL, st);
+    let tr = tr.absorb_into::<0, _>(Commit::<G>::LABEL, c);
+    Ok(tr.challenge::<G::Scalar>("Dleq.e")?.0)
+}
+
+/// FS proof of `st` with witness x on the typed transcript `tr`.
+pub fn prove<G: PrimeGroup, O: Oracle, R: RngCore + ?Sized>(tr: Round<O>, st: &Statement<G>, x: &G::Scalar, rng: &mut R) -> Result<FsProof> {
+    let (c, r) = Dleq::<G>::commit(st, x, rng);
+    let e = challenge(tr, st, &c)?;
+    let z = Dleq::<G>::respond(st, x, &r, &e);
+    Ok(FsProof { m: vec![encode(&c)], z: vec![encode(&z)], rho: 1, b: 0 })
+}
+
+pub fn verify<G: PrimeGroup, O: Oracle>(tr: Round<O>, st: &Statement<G>, proof: &FsProof) -> bool {
+    if !proof.is_well_formed() || proof.m.len() != 1 { return false; }
+    let (Some(c), Some(z)) = (decode_exact::<Commit<G>>(&proof.m[0]), decode_exact::<G::Scalar>(&proof.z[0])) else {
+        return false;
+    };
+    match challenge(tr, st, &c) {
+        Ok(e) => Dleq::<G>::verify(st, &c, &e, &z),
+        Err(_) => false,
+    }
+}
+
+/// Fischlin proof of `st` (`sigma::prove_fischlin` for `Dleq<G>`).
+pub fn prove_fischlin<G: PrimeGroup, RO: RandomOracle, R: RngCore + ?Sized>(
+    ro: RO,
+    params: FischlinParams,
+    st: &Statement<G>,
+    x: &G::Scalar,
+    sid: &[u8],
+    rng: &mut R,
+) -> Result<FischlinProof> {
+    sigma::prove_fischlin::<Dleq<G>, _, _>(ro, params, st, x, sid, rng)
+}
+
+pub fn verify_fischlin<G: PrimeGroup, RO: RandomOracle>(ro: RO, params: FischlinParams, st: &Statement<G>, sid: &[u8], proof: &FischlinProof) -> bool {
+    sigma::verify_fischlin::<Dleq<G>, _>(ro, params, st, sid, proof)
+}
+
+/// The combined statement (g, Σ d_i·h_i, a, Σ d_i·b_i) with weights d_i drawn from `tr` after
+/// absorbing `batch`, and the transcript moved on to the DLEQ round.
+pub fn combine<G: PrimeGroup, O: Oracle>(tr: BatchRound<O>, batch: &Batch<G>) -> Result<(Statement<G>, Round<O>)> {
+    let mut tr = tr.absorb_into::<0, _>(Batch::<G>::LABEL, batch);
+    let (mut h, mut b) = (G::identity(), G::identity());
+    for (i, (h_i, b_i)) in batch.pairs.iter().enumerate() {
+        let (d, next) = tr.absorb_bytes("Dleq.Batch.i", &(i as u64).to_le_bytes()).challenge::<G::Scalar>("Dleq.Batch.d")?;
+        tr = next;
+        h = h + *h_i * d;
+        b = b + *b_i * d;
+    }
+    Ok((Statement { g: batch.g, h, a: batch.a, b }, tr.next_round()))
+}
+
+/// FS proof for every pair of `batch` at once, on one typed transcript.
+pub fn prove_batch<G: PrimeGroup, O: Oracle, R: RngCore + ?Sized>(tr: BatchRound<O>, batch: &Batch<G>, x: &G::Scalar, rng: &mut R) -> Result<FsProof> {
+    let (st, tr) = combine(tr, batch)?;
+    prove(tr, &st, x, rng)
+}
+
+pub fn verify_batch<G: PrimeGroup, O: Oracle>(tr: BatchRound<O>, batch: &Batch<G>, proof: &FsProof) -> bool {
+    match combine(tr, batch) {
+        Ok((st, tr)) => verify(tr, &st, proof),
+        Err(_) => false,
+    }
+}
+
+/// Fischlin proof for every pair of `batch`: weights from `weights`, the proof under `ro`.
+pub fn prove_batch_fischlin<G: PrimeGroup, O: Oracle, RO: RandomOracle, R: RngCore + ?Sized>(
+    weights: BatchRound<O>,
+    ro: RO,
+    params: FischlinParams,
+    batch: &Batch<G>,
+    x: &G::Scalar,
+    sid: &[u8],
+    rng: &mut R,
+) -> Result<FischlinProof> {
+    let (st, _) = combine(weights, batch)?;
+    prove_fischlin(ro, params, &st, x, sid, rng)
+}
+
+pub fn verify_batch_fischlin<G: PrimeGroup, O: Oracle, RO: RandomOracle>(
+    weights: BatchRound<O>,
+    ro: RO,
+    params: FischlinParams,
+    batch: &Batch<G>,
+    sid: &[u8],
+    proof: &FischlinProof,
+) -> bool {
+    match combine(weights, batch) {
+        Ok((st, _)) => verify_fischlin(ro, params, &st, sid, proof),
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,258 @@
+//! Maurer's generic proof of knowledge of a preimage under a group homomorphism: w ∈ F^n with
+//! φ(w) = X ∈ G^m, φ given as an m × n matrix of group elements, φ(w)_j = Σ_k M[j][k]·w_k.
+//!
+//! T = φ(r), z = r + e·w; the verifier checks φ(z) = T + e·X row by row, and two transcripts
+//! with e1 ≠ e2 give w = (z1 − z2) / (e1 − e2). Schnorr is the 1 × 1 matrix [G], Okamoto and
+//! Pedersen openings the row [G, H], DLEQ the column [g; h]; a linear relation among several
+//! witnesses is a sparse matrix, built with `Homomorphism::sparse`, whose absent entries mark
+//! where a witness does not occur. Absent entries are encoded as such (an `Option` tag), never
+//! as the identity, which the SEC1 backends cannot encode (see `groups`).
+//!
+//! As in `dleq`, `Statement` and `Commit` are `FsrBindable` messages making up `Round`, so
+//! `prove` / `verify` run on the typed `Transcript`, and `Linear<G>` is the `SigmaProtocol` for
+//! the `sigma` drivers. `respond_stream` is the incremental Fischlin responder (z += w per
+//! challenge tried, no scalar multiplication), used by `prove_fischlin`; its proofs verify
+//! under `sigma::verify_fischlin::<Linear<G>>`.
+
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+
+use crate::batch::{Equation, LinearSigma};
+use crate::fischlin::{FischlinOracle, FischlinParams};
+use crate::fischlin_proof::FischlinProof;
+use crate::fs_proof::FsProof;
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::runtime::RandomOracle;
+use crate::sigma::{self, bind_commitment, decode_exact, encode, SigmaProtocol};
+use crate::{CanonicalDecode, CanonicalEncode, Direction, FsrBindable, Message, Oracle, Result, Transcript};
+
+const STATEMENT: u128 = 1 << 0;
+const COMMIT: u128 = 1 << 1;
+const ROUND: u128 = STATEMENT | COMMIT;
+
+/// One round: statement and commitment pending.
+pub type Round<O> = Transcript<ROUND, O>;
+
+/// The matrix of φ: `rows()` × `cols()` entries, each a group element or absent (zero).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Homomorphism<G> {
+    rows: Vec<Vec<Option<G>>>,
+}
+
+impl<G: PrimeGroup> Homomorphism<G> {
+    /// φ from dense rows; panics as `sparse` does.
+    pub fn new(rows: Vec<Vec<G>>) -> Self {
+        Self::sparse(rows.into_iter().map(|r| r.into_iter().map(Some).collect()).collect())
+    }
+
+    /// φ from rows with absent entries; panics unless there is at least one row, all rows have
+    /// the same non-zero length, every row has an entry and no entry is the identity.
+    pub fn sparse(rows: Vec<Vec<Option<G>>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(cols > 0 && rows.iter().all(|r| r.len() == cols), "linear: need a non-empty rectangular matrix");
+        assert!(rows.iter().all(|r| r.iter().any(Option::is_some)), "linear: every row needs an entry");
+        assert!(rows.iter().flatten().flatten().all(|m| !m.is_identity()), "linear: leave zero entries absent");
+        Homomorphism { rows }
+    }
+
+    pub fn rows(&self) -> usize { self.rows.len() }
+    pub fn cols(&self) -> usize { self.rows[0].len() }
+    pub fn matrix(&self) -> &[Vec<Option<G>>] { &self.rows }
+
+    /// φ(w); `w` must have `cols()` entries.
+    pub fn apply(&self, w: &[G::Scalar]) -> Vec<G> {
+        assert_eq!(w.len(), self.cols(), "linear: witness length must match the matrix");
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(w)
+                    .filter_map(|(m, wk)| Some((*m)? * *wk))
+                    .fold(G::identity(), |acc, p| acc + p)
+            })
+            .collect()
+    }
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Homomorphism<G> {
+    fn encode(&self, out: &mut Vec<u8>) { self.rows.encode(out); }
+}
+
+/// φ(w) = x.
+#[derive(Clone, Debug, PartialEq, Eq, FsrBindable)]
+#[bind(prefix = "Linear.Statement", core = "crate")]
+pub struct Statement<G: PrimeGroup> {
+    #[bind(ob = 0)] pub phi: Homomorphism<G>,
+    pub x: Vec<G>,
+}
+impl<G: PrimeGroup> Message for Statement<G> {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Linear.Statement";
+}
+
+impl<G: PrimeGroup> Statement<G> {
+    /// x = φ(w), for a prover holding w.
+    pub fn image(phi: Homomorphism<G>, w: &[G::Scalar]) -> Self {
+        let x = phi.apply(w);
+        Statement { phi, x }
+    }
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Statement<G> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.phi.encode(out);
+        self.x.encode(out);
+    }
+}
+
+/// T = φ(r), one element per row.
+#[derive(Clone, Debug, PartialEq, Eq, FsrBindable)]
+#[bind(prefix = "Linear.Commit", core = "crate")]
+pub struct Commit<G: PrimeGroup> {
+    #[bind(ob = 1)] pub t: Vec<G>,
+}
+impl<G: PrimeGroup> Message for Commit<G> {
+    const DIR: Direction = Direction::ProverToVerifier;
+    const LABEL: &'static str = "Linear.Commit";
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Commit<G> {
+    fn encode(&self, out: &mut Vec<u8>) { self.t.encode(out); }
+}
+impl<G: PrimeGroup> CanonicalDecode for Commit<G> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        Some(Commit { t: Vec::decode(input)? })
+    }
+}
+
+pub struct Linear<G>(PhantomData<G>);
+
+impl<G: PrimeGroup> SigmaProtocol for Linear<G> {
+    type Statement = Statement<G>;
+    type Witness = Vec<G::Scalar>;
+    type Commitment = Commit<G>;
+    type Challenge = G::Scalar;
+    type Response = Vec<G::Scalar>;
+    type State = Vec<G::Scalar>;
+
+    fn commit<R: RngCore + ?Sized>(st: &Statement<G>, _w: &Vec<G::Scalar>, rng: &mut R) -> (Commit<G>, Vec<G::Scalar>) {
+        let r: Vec<G::Scalar> = (0..st.phi.cols()).map(|_| G::Scalar::random(rng)).collect();
+        (Commit { t: st.phi.apply(&r) }, r)
+    }
+
+    fn respond(_st: &Statement<G>, w: &Vec<G::Scalar>, r: &Vec<G::Scalar>, e: &G::Scalar) -> Vec<G::Scalar> {
+        r.iter().zip(w).map(|(rk, wk)| *rk + *e * *wk).collect()
+    }
+
+    fn verify(st: &Statement<G>, c: &Commit<G>, e: &G::Scalar, z: &Vec<G::Scalar>) -> bool {
+        let m = st.phi.rows();
+        if st.x.len() != m || c.t.len() != m || z.len() != st.phi.cols() { return false; }
+        st.phi.apply(z).iter().zip(&c.t).zip(&st.x).all(|((lhs, t), x)| *lhs == *t + *x * *e)
+    }
+
+    fn simulate<R: RngCore + ?Sized>(st: &Statement<G>, e: &G::Scalar, rng: &mut R) -> (Commit<G>, Vec<G::Scalar>) {
+        let z: Vec<G::Scalar> = (0..st.phi.cols()).map(|_| G::Scalar::random(rng)).collect();
+        let t = st.phi.apply(&z).into_iter().zip(&st.x).map(|(p, x)| p - *x * *e).collect();
+        (Commit { t }, z)
+    }
+
+    fn extract(st: &Statement<G>, c: &Commit<G>, transcripts: &[(G::Scalar, Vec<G::Scalar>)]) -> Option<Vec<G::Scalar>> {
+        let [(e1, z1), (e2, z2)] = transcripts else { return None };
+        if !Self::verify(st, c, e1, z1) || !Self::verify(st, c, e2, z2) { return None; }
+        let inv = (*e1 - *e2).invert()?;
+        Some(z1.iter().zip(z2).map(|(a, b)| (*a - *b) * inv).collect())
+    }
+}
+
+impl<G: PrimeGroup> LinearSigma for Linear<G> {
+    type Group = G;
+
+    /// One equation per row, Σ_k M[j][k]·z_k − T_j − e·x_j = 0 (absent entries skipped).
+    fn equations(st: &Statement<G>, c: &Commit<G>, e: &G::Scalar, z: &Vec<G::Scalar>, out: &mut Vec<Equation<G>>) -> bool {
+        let m = st.phi.rows();
+        if st.x.len() != m || c.t.len() != m || z.len() != st.phi.cols() { return false; }
+        for ((row, t), x) in st.phi.matrix().iter().zip(&c.t).zip(&st.x) {
+            let mut terms: Vec<_> = row.iter().zip(z).filter_map(|(p, zk)| Some((*zk, (*p)?))).collect();
+            terms.extend([(-G::Scalar::one(), *t), (-*e, *x)]);
+            out.push(Equation { base: G::Scalar::zero(), terms });
+        }
+        true
+    }
+}
+
+/// Fischlin responder for one repetition: the encoded z for e = 0, 1, 2, … on successive calls,
+/// starting from z = r and adding w each time, in the order `search_round_stream` tries them.
+pub fn respond_stream<G: PrimeGroup>(w: &[G::Scalar], r: &[G::Scalar]) -> impl FnMut() -> Vec<u8> {
+    let w = w.to_vec();
+    let mut z = r.to_vec();
+    move || {
+        let out = encode(&z);
+        for (zk, wk) in z.iter_mut().zip(&w) { *zk = *zk + *wk; }
+        out
+    }
+}
+
+fn challenge<G: PrimeGroup, O: Oracle>(tr: Round<O>, st: &Statement<G>, c: &Commit<G>) -> Result<G::Scalar> {
+    let tr = tr.absorb_into::<COMMIT, _>(Statement::<G>::LABEL, st);
+    let tr = tr.absorb_into::<0, _>(Commit::<G>::LABEL, c);
+    Ok(tr.challenge::<G::Scalar>("Linear.e")?.0)
+}
+
+/// FS proof of `st` with witness w on the typed transcript `tr`.
+pub fn prove<G: PrimeGroup, O: Oracle, R: RngCore + ?Sized>(tr: Round<O>, st: &Statement<G>, w: &Vec<G::Scalar>, rng: &mut R) -> Result<FsProof> {
+    let (c, r) = Linear::<G>::commit(st, w, rng);
+    let e = challenge(tr, st, &c)?;
+    let z = Linear::<G>::respond(st, w, &r, &e);
+    Ok(FsProof { m: vec![encode(&c)], z: vec![encode(&z)], rho: 1, b: 0 })
+}
+
+pub fn verify<G: PrimeGroup, O: Oracle>(tr: Round<O>, st: &Statement<G>, proof: &FsProof) -> bool {
+    if !proof.is_well_formed() || proof.m.len() != 1 { return false; }
+    let (Some(c), Some(z)) = (decode_exact::<Commit<G>>(&proof.m[0]), decode_exact::<Vec<G::Scalar>>(&proof.z[0])) else {
+        return false;
+    };
+    match challenge(tr, st, &c) {
+        Ok(e) => Linear::<G>::verify(st, &c, &e, &z),
+        Err(_) => false,
+    }
+}
+
+/// Fischlin proof of `st`: the transcript of `sigma::prove_fischlin::<Linear<G>>`, with the
+/// search run on `respond_stream`.
+pub fn prove_fischlin<G: PrimeGroup, RO: RandomOracle, R: RngCore + ?Sized>(
+    ro: RO,
+    params: FischlinParams,
+    st: &Statement<G>,
+    w: &Vec<G::Scalar>,
+    sid: &[u8],
+    rng: &mut R,
+) -> Result<FischlinProof> {
+    let mut oracle = FischlinOracle::new(ro, params);
+    oracle.begin(&encode(st), sid);
+
+    let rho = params.rho as usize;
+    let mut m = Vec::with_capacity(rho);
+    let mut states = Vec::with_capacity(rho);
+    for _ in 0..rho {
+        let (c, r) = Linear::<G>::commit(st, w, rng);
+        let c_bytes = encode(&c);
+        oracle.push_first_message(&c_bytes)?;
+        bind_commitment::<Linear<G>>(&mut oracle, st, &c)?;
+        m.push(c_bytes);
+        states.push(r);
+    }
+    oracle.seal_first_messages()?;
+
+    let (mut e, mut z) = (Vec::with_capacity(rho), Vec::with_capacity(rho));
+    for (i, r) in states.iter().enumerate() {
+        let (e_i, z_i) = oracle.search_round_stream(i as u32, respond_stream::<G>(w, r))?;
+        e.push(e_i);
+        z.push(z_i);
+    }
+    Ok(FischlinProof { m, e, z, b: params.b, rho: params.rho })
+}
+
+pub fn verify_fischlin<G: PrimeGroup, RO: RandomOracle>(ro: RO, params: FischlinParams, st: &Statement<G>, sid: &[u8], proof: &FischlinProof) -> bool {
+    sigma::verify_fischlin::<Linear<G>, _>(ro, params, st, sid, proof)
+}
@@ -0,0 +1,11 @@
+//! `SigmaProtocol` implementations: base protocols generic over `PrimeGroup`, and combinators
+//! built from other `SigmaProtocol`s.
+
+pub mod schnorr;      // knowledge of a discrete log, y = x·G
+pub mod and;          // AND composition with a shared challenge (binary and n-ary)
+pub mod or;           // OR composition (CDS): simulated branch, split challenge e = e0 + e1
+pub mod threshold;    // k-of-n composition (CDS94): Shamir-shared challenge, verified by interpolation
+pub mod dleq;         // Chaum–Pedersen DLEQ on the typed Transcript, batched by random linear combination
+pub mod linear;       // Maurer: preimage of a homomorphism given as a matrix of generators
+pub mod range;        // v ∈ [0, 2^n) for a Pedersen commitment: per-bit OR proofs, weighted sum by construction
+pub mod compressed;   // Attema–Cramer compressed Σ-protocol for linear forms: log-size folding on the typed Transcript
@@ -0,0 +1,131 @@
+//! OR composition (Cramer–Damgård–Schoenmakers): prove one of two statements without revealing
+//! which.
+//!
+//! The prover simulates the branch it has no witness for on a random challenge, runs the other
+//! honestly, and answers the oracle challenge e with the real branch's share e − e_fake. The
+//! response is `(e0, (z0, z1))`: the verifier recomputes e1 = e − e0 and checks both branches,
+//! so the split always sums to the oracle challenge. The fake branch needs only `simulate`, and
+//! the verifier only each branch's `verify`. Both commitments are obligations, bound under
+//! `or.0` / `or.1` before the challenge. With `LinearSigma` branches the equations are those of
+//! branch 0 at e0 and branch 1 at e − e0, so the split is checked by construction here too.
+
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+
+use crate::batch::{Equation, LinearSigma};
+use crate::groups::PrimeField;
+use crate::sigma::{Binder, Labels, SigmaProtocol};
+
+/// The witness of an OR statement: one for either branch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub struct Or<P1, P2>(PhantomData<(P1, P2)>);
+
+impl<P1, P2> SigmaProtocol for Or<P1, P2>
+where
+    P1: SigmaProtocol,
+    P2: SigmaProtocol<Challenge = P1::Challenge>,
+{
+    type Statement = (P1::Statement, P2::Statement);
+    type Witness = Either<P1::Witness, P2::Witness>;
+    type Commitment = (P1::Commitment, P2::Commitment);
+    type Challenge = P1::Challenge;
+    /// `(e0, (z0, z1))`; e1 = e − e0.
+    type Response = (P1::Challenge, (P1::Response, P2::Response));
+    /// The real branch's state, with the simulated branch's challenge and response.
+    type State = Either<(P1::State, P1::Challenge, P2::Response), (P2::State, P1::Challenge, P1::Response)>;
+
+    /// Any `(n1 − 1)(n2 − 1) + 1` transcripts with distinct e hold `n1` distinct e0 or `n2`
+    /// distinct e1 (2 for two special-sound branches).
+    const SPECIAL_SOUNDNESS: usize = (P1::SPECIAL_SOUNDNESS - 1) * (P2::SPECIAL_SOUNDNESS - 1) + 1;
+
+    fn commit<R: RngCore + ?Sized>(x: &Self::Statement, w: &Self::Witness, rng: &mut R) -> (Self::Commitment, Self::State) {
+        let e_fake = P1::Challenge::random(rng);
+        match w {
+            Either::Left(w) => {
+                let (a1, s) = P1::commit(&x.0, w, rng);
+                let (a2, z2) = P2::simulate(&x.1, &e_fake, rng);
+                ((a1, a2), Either::Left((s, e_fake, z2)))
+            }
+            Either::Right(w) => {
+                let (a2, s) = P2::commit(&x.1, w, rng);
+                let (a1, z1) = P1::simulate(&x.0, &e_fake, rng);
+                ((a1, a2), Either::Right((s, e_fake, z1)))
+            }
+        }
+    }
+
+    fn respond(x: &Self::Statement, w: &Self::Witness, state: &Self::State, e: &Self::Challenge) -> Self::Response {
+        match (w, state) {
+            (Either::Left(w), Either::Left((s, e2, z2))) => {
+                let e1 = *e - *e2;
+                (e1, (P1::respond(&x.0, w, s, &e1), z2.clone()))
+            }
+            (Either::Right(w), Either::Right((s, e1, z1))) => {
+                let e2 = *e - *e1;
+                (*e1, (z1.clone(), P2::respond(&x.1, w, s, &e2)))
+            }
+            _ => panic!("Or: state from a commit with the other branch's witness"),
+        }
+    }
+
+    fn verify(x: &Self::Statement, a: &Self::Commitment, e: &Self::Challenge, z: &Self::Response) -> bool {
+        let (e1, (z1, z2)) = z;
+        P1::verify(&x.0, &a.0, e1, z1) && P2::verify(&x.1, &a.1, &(*e - *e1), z2)
+    }
+
+    fn simulate<R: RngCore + ?Sized>(x: &Self::Statement, e: &Self::Challenge, rng: &mut R) -> (Self::Commitment, Self::Response) {
+        let e1 = P1::Challenge::random(rng);
+        let (a1, z1) = P1::simulate(&x.0, &e1, rng);
+        let (a2, z2) = P2::simulate(&x.1, &(*e - e1), rng);
+        ((a1, a2), (e1, (z1, z2)))
+    }
+
+    fn extract(x: &Self::Statement, a: &Self::Commitment, transcripts: &[(Self::Challenge, Self::Response)]) -> Option<Self::Witness> {
+        if !transcripts.iter().all(|(e, z)| Self::verify(x, a, e, z)) { return None; }
+        let left = distinct_by(transcripts.iter().map(|(_, (e1, (z1, _)))| (*e1, z1.clone())), P1::SPECIAL_SOUNDNESS);
+        if let Some(t) = left {
+            return P1::extract(&x.0, &a.0, &t).map(Either::Left);
+        }
+        let right = distinct_by(transcripts.iter().map(|(e, (e1, (_, z2)))| (*e - *e1, z2.clone())), P2::SPECIAL_SOUNDNESS);
+        P2::extract(&x.1, &a.1, &right?).map(Either::Right)
+    }
+
+    fn require(x: &Self::Statement, labels: &mut Labels) {
+        labels.scope("or.0", |l| P1::require(&x.0, l));
+        labels.scope("or.1", |l| P2::require(&x.1, l));
+    }
+
+    fn bind(x: &Self::Statement, a: &Self::Commitment, binder: &mut Binder<'_>) {
+        binder.scope("or.0", |b| P1::bind(&x.0, &a.0, b));
+        binder.scope("or.1", |b| P2::bind(&x.1, &a.1, b));
+    }
+}
+
+impl<P1, P2> LinearSigma for Or<P1, P2>
+where
+    P1: LinearSigma,
+    P2: LinearSigma<Group = P1::Group>,
+{
+    type Group = P1::Group;
+
+    fn equations(x: &Self::Statement, a: &Self::Commitment, e: &Self::Challenge, z: &Self::Response, out: &mut Vec<Equation<P1::Group>>) -> bool {
+        let (e1, (z1, z2)) = z;
+        P1::equations(&x.0, &a.0, e1, z1, out) && P2::equations(&x.1, &a.1, &(*e - *e1), z2, out)
+    }
+}
+
+/// The first `n` branch transcripts with pairwise distinct challenges, if there are that many.
+fn distinct_by<F: PrimeField, Z>(transcripts: impl Iterator<Item = (F, Z)>, n: usize) -> Option<Vec<(F, Z)>> {
+    let mut out: Vec<(F, Z)> = Vec::with_capacity(n);
+    for (e, z) in transcripts {
+        if out.len() == n { break; }
+        if out.iter().all(|(f, _)| *f != e) { out.push((e, z)); }
+    }
+    (out.len() == n).then_some(out)
+}
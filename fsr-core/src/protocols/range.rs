@@ -0,0 +1,220 @@
+//! Range proofs by bit decomposition: a Pedersen commitment C = v·G + s·H opens to some
+//! v ∈ [0, 2^n), for n ≤ 64, G the group's generator.
+//!
+//! The prover commits to every bit, C_i = b_i·G + s_i·H, with the blinds chosen so that
+//! Σ 2^i·s_i = s; then Σ 2^i·C_i = C. Only C_1 … C_{n−1} are sent: the verifier derives
+//! C_0 = C − Σ_{i≥1} 2^i·C_i, so the weighted sum holds by construction. Each bit is an
+//! `or::Or` of two `linear::Linear` openings under φ(s) = s·H, "C_i = s_i·H" (b_i = 0) or
+//! "C_i − G = s_i·H" (b_i = 1), all n sharing the challenge. Any opening of C then has
+//! v = Σ 2^i·b_i < 2^n, since H's discrete log to G is unknown.
+//!
+//! Encoding: the commitment is C_1 … C_{n−1} followed by the n pairs of branch commitments,
+//! the response the n triples `(e0, z0, z1)`, each with one u32 count and no per-branch
+//! framing. `Range<G>` is a `SigmaProtocol`, so `sigma::prove_fs` / `sigma::prove_fischlin`
+//! give FS and Fischlin proofs, and a `LinearSigma` (the 2n branch equations), so FS proofs
+//! batch-verify with `batch::verify_fs_batch`.
+
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+
+use crate::batch::{Equation, LinearSigma};
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::protocols::linear::{self, Homomorphism, Linear};
+use crate::protocols::or::{Either, Or};
+use crate::sigma::SigmaProtocol;
+use crate::{CanonicalDecode, CanonicalEncode};
+
+type Bit<G> = Or<Linear<G>, Linear<G>>;
+type BitStatement<G> = <Bit<G> as SigmaProtocol>::Statement;
+type BitWitness<G> = <Bit<G> as SigmaProtocol>::Witness;
+type BitState<G> = <Bit<G> as SigmaProtocol>::State;
+
+/// C = v·G + s·H.
+pub fn pedersen<G: PrimeGroup>(h: G, v: u64, s: G::Scalar) -> G {
+    G::generator() * G::Scalar::from_u64(v) + h * s
+}
+
+/// C opens to a value below 2^n under the Pedersen generators (G, h).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Statement<G> {
+    pub h: G,
+    pub n: usize,
+    pub c: G,
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Statement<G> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.h.encode(out);
+        (self.n as u64).encode(out);
+        self.c.encode(out);
+    }
+}
+
+impl<G> Statement<G> {
+    fn is_valid(&self) -> bool { 1 <= self.n && self.n <= 64 }
+}
+
+/// The opening (v, s) of C.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Opening<S> {
+    pub v: u64,
+    pub s: S,
+}
+
+/// The bit commitments C_1 … C_{n−1} and, per bit, the commitments of both OR branches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Commit<G> {
+    pub bits: Vec<G>,
+    pub a: Vec<(G, G)>,
+}
+
+impl<G: PrimeGroup> CanonicalEncode for Commit<G> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        debug_assert_eq!(self.a.len(), self.bits.len() + 1);
+        self.bits.encode(out);
+        for pair in &self.a { pair.encode(out); }
+    }
+}
+impl<G: PrimeGroup> CanonicalDecode for Commit<G> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let mut rest = *input;
+        let bits = Vec::<G>::decode(&mut rest)?;
+        let a = (0..=bits.len()).map(|_| <(G, G)>::decode(&mut rest)).collect::<Option<_>>()?;
+        *input = rest;
+        Some(Commit { bits, a })
+    }
+}
+
+/// The prover's per-bit OR statements, witnesses and states (`respond` runs once per Fischlin
+/// try, so it does no group operations).
+pub struct State<G: PrimeGroup> {
+    statements: Vec<BitStatement<G>>,
+    witnesses: Vec<BitWitness<G>>,
+    states: Vec<BitState<G>>,
+}
+
+pub struct Range<G>(PhantomData<G>);
+
+impl<G: PrimeGroup> SigmaProtocol for Range<G> {
+    type Statement = Statement<G>;
+    type Witness = Opening<G::Scalar>;
+    type Commitment = Commit<G>;
+    type Challenge = G::Scalar;
+    /// `(e0, (z0, z1))` per bit, as in `Or`.
+    type Response = Vec<(G::Scalar, (G::Scalar, G::Scalar))>;
+    type State = State<G>;
+
+    fn commit<R: RngCore + ?Sized>(x: &Statement<G>, w: &Opening<G::Scalar>, rng: &mut R) -> (Commit<G>, State<G>) {
+        assert!(x.is_valid(), "Range: need 1 <= n <= 64");
+        assert!(x.n == 64 || w.v >> x.n == 0, "Range: value does not fit in n bits");
+        // s_0 absorbs the difference so that Σ 2^i·s_i = s.
+        let mut blinds: Vec<G::Scalar> = (0..x.n).map(|_| G::Scalar::random(rng)).collect();
+        blinds[0] = w.s - (1..x.n).fold(G::Scalar::zero(), |acc, i| acc + pow2::<G::Scalar>(i) * blinds[i]);
+        let bits: Vec<G> = (0..x.n).map(|i| pedersen(x.h, (w.v >> i) & 1, blinds[i])).collect();
+
+        let statements: Vec<_> = bits.iter().map(|c_i| bit_statement(x.h, *c_i)).collect();
+        let (mut a, mut witnesses, mut states) = (Vec::with_capacity(x.n), Vec::with_capacity(x.n), Vec::with_capacity(x.n));
+        for (i, (xi, s_i)) in statements.iter().zip(&blinds).enumerate() {
+            let wi = if (w.v >> i) & 1 == 0 { Either::Left(vec![*s_i]) } else { Either::Right(vec![*s_i]) };
+            let ((a0, a1), st) = Bit::<G>::commit(xi, &wi, rng);
+            a.push((a0.t[0], a1.t[0]));
+            witnesses.push(wi);
+            states.push(st);
+        }
+        (Commit { bits: bits[1..].to_vec(), a }, State { statements, witnesses, states })
+    }
+
+    fn respond(_x: &Statement<G>, _w: &Opening<G::Scalar>, state: &State<G>, e: &G::Scalar) -> Self::Response {
+        state
+            .statements
+            .iter()
+            .zip(&state.witnesses)
+            .zip(&state.states)
+            .map(|((xi, wi), st)| {
+                let (e0, (z0, z1)) = Bit::<G>::respond(xi, wi, st, e);
+                (e0, (z0[0], z1[0]))
+            })
+            .collect()
+    }
+
+    fn verify(x: &Statement<G>, a: &Commit<G>, e: &G::Scalar, z: &Self::Response) -> bool {
+        let Some(bits) = all_bits(x, a) else { return false };
+        if a.a.len() != x.n || z.len() != x.n { return false; }
+        bits.iter().zip(&a.a).zip(z).all(|((c_i, a_i), z_i)| {
+            Bit::<G>::verify(&bit_statement(x.h, *c_i), &unpack_commit(a_i), e, &unpack_response(z_i))
+        })
+    }
+
+    fn simulate<R: RngCore + ?Sized>(x: &Statement<G>, e: &G::Scalar, rng: &mut R) -> (Commit<G>, Self::Response) {
+        // Honest C_1 … C_{n−1} have independent uniform blinds, so they are uniform whatever v is.
+        let sent: Vec<G> = (1..x.n).map(|_| x.h * G::Scalar::random(rng)).collect();
+        let mut commit = Commit { bits: sent, a: Vec::with_capacity(x.n) };
+        let bits = all_bits(x, &commit).unwrap_or_default();
+        let mut z = Vec::with_capacity(x.n);
+        for c_i in &bits {
+            let ((a0, a1), (e0, (z0, z1))) = Bit::<G>::simulate(&bit_statement(x.h, *c_i), e, rng);
+            commit.a.push((a0.t[0], a1.t[0]));
+            z.push((e0, (z0[0], z1[0])));
+        }
+        (commit, z)
+    }
+
+    fn extract(x: &Statement<G>, a: &Commit<G>, transcripts: &[(G::Scalar, Self::Response)]) -> Option<Opening<G::Scalar>> {
+        let [(e1, z1), (e2, z2)] = transcripts else { return None };
+        if !Self::verify(x, a, e1, z1) || !Self::verify(x, a, e2, z2) { return None; }
+        let bits = all_bits(x, a)?;
+        let (mut v, mut s) = (0u64, G::Scalar::zero());
+        for (i, c_i) in bits.iter().enumerate() {
+            let ti = [(*e1, unpack_response(&z1[i])), (*e2, unpack_response(&z2[i]))];
+            let (b_i, s_i) = match Bit::<G>::extract(&bit_statement(x.h, *c_i), &unpack_commit(&a.a[i]), &ti)? {
+                Either::Left(s_i) => (0u64, s_i[0]),
+                Either::Right(s_i) => (1, s_i[0]),
+            };
+            v |= b_i << i;
+            s = s + pow2::<G::Scalar>(i) * s_i;
+        }
+        Some(Opening { v, s })
+    }
+}
+
+impl<G: PrimeGroup> LinearSigma for Range<G> {
+    type Group = G;
+
+    fn equations(x: &Statement<G>, a: &Commit<G>, e: &G::Scalar, z: &Self::Response, out: &mut Vec<Equation<G>>) -> bool {
+        let Some(bits) = all_bits(x, a) else { return false };
+        if a.a.len() != x.n || z.len() != x.n { return false; }
+        bits.iter().zip(&a.a).zip(z).all(|((c_i, a_i), z_i)| {
+            Bit::<G>::equations(&bit_statement(x.h, *c_i), &unpack_commit(a_i), e, &unpack_response(z_i), out)
+        })
+    }
+}
+
+/// 2^i in the scalar field, i < 64.
+fn pow2<F: PrimeField>(i: usize) -> F {
+    F::from_u64(1 << i)
+}
+
+/// C_0 … C_{n−1}, with C_0 = C − Σ_{i≥1} 2^i·C_i; `None` if the commitment has the wrong shape.
+fn all_bits<G: PrimeGroup>(x: &Statement<G>, a: &Commit<G>) -> Option<Vec<G>> {
+    if !x.is_valid() || a.bits.len() != x.n - 1 { return None; }
+    let c_0 = a.bits.iter().enumerate().fold(x.c, |acc, (i, c_i)| acc - *c_i * pow2::<G::Scalar>(i + 1));
+    Some(core::iter::once(c_0).chain(a.bits.iter().copied()).collect())
+}
+
+/// "C_i = s·H" or "C_i − G = s·H".
+fn bit_statement<G: PrimeGroup>(h: G, c_i: G) -> BitStatement<G> {
+    let phi = Homomorphism::new(vec![vec![h]]);
+    (
+        linear::Statement { phi: phi.clone(), x: vec![c_i] },
+        linear::Statement { phi, x: vec![c_i - G::generator()] },
+    )
+}
+
+fn unpack_commit<G: PrimeGroup>((a0, a1): &(G, G)) -> (linear::Commit<G>, linear::Commit<G>) {
+    (linear::Commit { t: vec![*a0] }, linear::Commit { t: vec![*a1] })
+}
+
+fn unpack_response<F: PrimeField>((e0, (z0, z1)): &(F, (F, F))) -> (F, (Vec<F>, Vec<F>)) {
+    (*e0, (vec![*z0], vec![*z1]))
+}
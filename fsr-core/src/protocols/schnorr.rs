@@ -0,0 +1,57 @@
+//! Schnorr's protocol for y = w·G over any `PrimeGroup`, G the group's generator.
+//!
+//! a = r·G, z = r + e·w; the verifier checks z·G = a + e·y. Two transcripts (a, e1, z1),
+//! (a, e2, z2) with e1 ≠ e2 give w = (z1 − z2) / (e1 − e2). As a `LinearSigma` the check is the one
+//! equation z·G − a − e·y = 0, so FS proofs batch-verify with `batch::verify_fs_batch`.
+
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+
+use crate::batch::{Equation, LinearSigma};
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::sigma::SigmaProtocol;
+
+pub struct Schnorr<G>(PhantomData<G>);
+
+impl<G: PrimeGroup> SigmaProtocol for Schnorr<G> {
+    type Statement = G;
+    type Witness = G::Scalar;
+    type Commitment = G;
+    type Challenge = G::Scalar;
+    type Response = G::Scalar;
+    type State = G::Scalar;
+
+    fn commit<R: RngCore + ?Sized>(_y: &G, _w: &G::Scalar, rng: &mut R) -> (G, G::Scalar) {
+        let r = G::Scalar::random(rng);
+        (G::generator() * r, r)
+    }
+
+    fn respond(_y: &G, w: &G::Scalar, r: &G::Scalar, e: &G::Scalar) -> G::Scalar {
+        *r + *e * *w
+    }
+
+    fn verify(y: &G, a: &G, e: &G::Scalar, z: &G::Scalar) -> bool {
+        G::generator() * *z == *a + *y * *e
+    }
+
+    fn simulate<R: RngCore + ?Sized>(y: &G, e: &G::Scalar, rng: &mut R) -> (G, G::Scalar) {
+        let z = G::Scalar::random(rng);
+        (G::generator() * z - *y * *e, z)
+    }
+
+    fn extract(y: &G, a: &G, transcripts: &[(G::Scalar, G::Scalar)]) -> Option<G::Scalar> {
+        let [(e1, z1), (e2, z2)] = transcripts else { return None };
+        if !Self::verify(y, a, e1, z1) || !Self::verify(y, a, e2, z2) { return None; }
+        Some((*z1 - *z2) * (*e1 - *e2).invert()?)
+    }
+}
+
+impl<G: PrimeGroup> LinearSigma for Schnorr<G> {
+    type Group = G;
+
+    fn equations(y: &G, a: &G, e: &G::Scalar, z: &G::Scalar, out: &mut Vec<Equation<G>>) -> bool {
+        out.push(Equation { base: *z, terms: vec![(-G::Scalar::one(), *a), (-*e, *y)] });
+        true
+    }
+}
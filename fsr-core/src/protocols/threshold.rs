@@ -0,0 +1,214 @@
+//! k-of-n threshold composition (Cramer–Damgård–Schoenmakers 1994): knowledge of witnesses for
+//! at least k of n statements, without revealing which.
+//!
+//! Branch i gets the challenge share e_i = f(i + 1) of a polynomial f over the scalar field with
+//! deg f ≤ n − k and f(0) = e. The prover fixes the shares of the n − k branches it simulates
+//! before seeing e; together with f(0) = e they determine f, hence the shares of its k real
+//! branches. The response carries every `(e_i, z_i)`; the verifier interpolates f from e and the
+//! first n − k shares, checks the other k shares lie on it, and checks every branch. All n
+//! commitments are obligations, bound under `thr.0` … `thr.{n-1}` before the challenge.
+//!
+//! These are runtime `Labels` / `Binder` obligations, not `FsrBindable` `ob =` bits as in `dleq`
+//! and `compressed`: a `Transcript`'s pending set is a `u128` fixed at compile time, and n is a
+//! property of the statement. Nothing is lost: `require` lists one path per branch from the
+//! statement's n, and `sigma::bind_commitment` refuses to prove (and `verify_fs` /
+//! `verify_fischlin` to accept) unless `bind` absorbed exactly those paths, in order, before the
+//! challenge is drawn. A commitment vector of the wrong length also fails `verify`.
+//!
+//! k = n is an AND of the n statements, k = 1 an n-way OR.
+
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+
+use crate::groups::PrimeField;
+use crate::protocols::or::Either;
+use crate::sigma::{Binder, Labels, SigmaProtocol};
+use crate::CanonicalEncode;
+
+/// k of the statements `x`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement<S> {
+    pub k: usize,
+    pub x: Vec<S>,
+}
+
+impl<S: CanonicalEncode> CanonicalEncode for Statement<S> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (self.k as u64).encode(out);
+        self.x.encode(out);
+    }
+}
+
+impl<S> Statement<S> {
+    fn is_valid(&self) -> bool { 1 <= self.k && self.k <= self.x.len() }
+}
+
+pub struct Threshold<P>(PhantomData<P>);
+
+impl<P: SigmaProtocol> SigmaProtocol for Threshold<P> {
+    type Statement = Statement<P::Statement>;
+    /// `(i, w_i)` for k distinct branches i.
+    type Witness = Vec<(usize, P::Witness)>;
+    type Commitment = Vec<P::Commitment>;
+    type Challenge = P::Challenge;
+    /// `(e_i, z_i)` for every branch.
+    type Response = Vec<(P::Challenge, P::Response)>;
+    /// Per branch: the real branch's state, or the simulated branch's share and response.
+    type State = Vec<Either<P::State, (P::Challenge, P::Response)>>;
+
+    /// Two transcripts with distinct e give polynomials agreeing on at most n − k shares, so at
+    /// least k branches see two distinct challenges (exact for 2-special branches).
+    const SPECIAL_SOUNDNESS: usize = P::SPECIAL_SOUNDNESS;
+
+    fn commit<R: RngCore + ?Sized>(x: &Self::Statement, w: &Self::Witness, rng: &mut R) -> (Self::Commitment, Self::State) {
+        assert!(x.is_valid(), "Threshold: need 1 <= k <= n");
+        assert_eq!(w.len(), x.k, "Threshold: exactly k witnesses");
+        let real = |i: usize| w.iter().find(|(j, _)| *j == i).map(|(_, w)| w);
+        assert_eq!((0..x.x.len()).filter(|&i| real(i).is_some()).count(), x.k, "Threshold: witness indices must be distinct and < n");
+
+        x.x.iter()
+            .enumerate()
+            .map(|(i, xi)| match real(i) {
+                Some(wi) => {
+                    let (a, s) = P::commit(xi, wi, rng);
+                    (a, Either::Left(s))
+                }
+                None => {
+                    let ei = P::Challenge::random(rng);
+                    let (a, z) = P::simulate(xi, &ei, rng);
+                    (a, Either::Right((ei, z)))
+                }
+            })
+            .unzip()
+    }
+
+    fn respond(x: &Self::Statement, w: &Self::Witness, state: &Self::State, e: &Self::Challenge) -> Self::Response {
+        // f through (0, e) and the simulated shares.
+        let mut points = vec![(P::Challenge::zero(), *e)];
+        points.extend(state.iter().enumerate().filter_map(|(i, s)| match s {
+            Either::Right((ei, _)) => Some((point(i), *ei)),
+            Either::Left(_) => None,
+        }));
+        state
+            .iter()
+            .enumerate()
+            .map(|(i, s)| match s {
+                Either::Right((ei, zi)) => (*ei, zi.clone()),
+                Either::Left(si) => {
+                    let ei = interpolate_at(&points, point(i)).expect("distinct evaluation points");
+                    let wi = w.iter().find(|(j, _)| *j == i).map(|(_, w)| w).expect("state from commit with this witness");
+                    (ei, P::respond(&x.x[i], wi, si, &ei))
+                }
+            })
+            .collect()
+    }
+
+    fn verify(x: &Self::Statement, a: &Self::Commitment, e: &Self::Challenge, z: &Self::Response) -> bool {
+        let n = x.x.len();
+        if !x.is_valid() || a.len() != n || z.len() != n { return false; }
+        let on_polynomial = shares_consistent(x.k, e, z.iter().map(|(ei, _)| *ei));
+        on_polynomial && x.x.iter().zip(a).zip(z).all(|((xi, ai), (ei, zi))| P::verify(xi, ai, ei, zi))
+    }
+
+    fn simulate<R: RngCore + ?Sized>(x: &Self::Statement, e: &Self::Challenge, rng: &mut R) -> (Self::Commitment, Self::Response) {
+        let n = x.x.len();
+        let free = n - x.k.min(n);
+        let mut points = vec![(P::Challenge::zero(), *e)];
+        points.extend((0..free).map(|i| (point(i), P::Challenge::random(rng))));
+        x.x.iter()
+            .enumerate()
+            .map(|(i, xi)| {
+                let ei = match points.get(i + 1) {
+                    Some((_, ei)) => *ei,
+                    None => interpolate_at(&points, point(i)).expect("distinct evaluation points"),
+                };
+                let (a, z) = P::simulate(xi, &ei, rng);
+                (a, (ei, z))
+            })
+            .unzip()
+    }
+
+    fn extract(x: &Self::Statement, a: &Self::Commitment, transcripts: &[(Self::Challenge, Self::Response)]) -> Option<Self::Witness> {
+        if !transcripts.iter().all(|(e, z)| Self::verify(x, a, e, z)) { return None; }
+        let mut witnesses = Vec::with_capacity(x.k);
+        for (i, (xi, ai)) in x.x.iter().zip(a).enumerate() {
+            if witnesses.len() == x.k { break; }
+            // This branch's transcripts with pairwise distinct shares.
+            let mut ti: Vec<(P::Challenge, P::Response)> = Vec::new();
+            for (_, z) in transcripts {
+                let (ei, zi) = &z[i];
+                if ti.len() < P::SPECIAL_SOUNDNESS && ti.iter().all(|(f, _)| f != ei) {
+                    ti.push((*ei, zi.clone()));
+                }
+            }
+            if ti.len() == P::SPECIAL_SOUNDNESS && let Some(wi) = P::extract(xi, ai, &ti) {
+                witnesses.push((i, wi));
+            }
+        }
+        (witnesses.len() == x.k).then_some(witnesses)
+    }
+
+    fn require(x: &Self::Statement, labels: &mut Labels) {
+        for (i, xi) in x.x.iter().enumerate() {
+            labels.scope(&format!("thr.{i}"), |l| P::require(xi, l));
+        }
+    }
+
+    fn bind(x: &Self::Statement, a: &Self::Commitment, binder: &mut Binder<'_>) {
+        for (i, (xi, ai)) in x.x.iter().zip(a).enumerate() {
+            binder.scope(&format!("thr.{i}"), |b| P::bind(xi, ai, b));
+        }
+    }
+}
+
+/// Evaluation point of branch i.
+fn point<F: PrimeField>(i: usize) -> F {
+    F::from_u64(i as u64 + 1)
+}
+
+/// Whether `(0, e)` and the shares `(i + 1, e_i)` lie on one polynomial of degree ≤ n − k:
+/// interpolate through e and the first n − k shares, evaluate at the remaining k points.
+fn shares_consistent<F: PrimeField>(k: usize, e: &F, shares: impl ExactSizeIterator<Item = F>) -> bool {
+    let free = shares.len() - k;
+    let mut points = vec![(F::zero(), *e)];
+    for (i, ei) in shares.enumerate() {
+        if i < free {
+            points.push((point(i), ei));
+        } else if interpolate_at(&points, point(i)) != Some(ei) {
+            return false;
+        }
+    }
+    true
+}
+
+/// The value at `x` of the polynomial of degree < `points.len()` through `points` (Lagrange);
+/// `None` if two points share an abscissa. The Lagrange denominators share one inversion.
+fn interpolate_at<F: PrimeField>(points: &[(F, F)], x: F) -> Option<F> {
+    let (mut nums, mut dens) = (Vec::with_capacity(points.len()), Vec::with_capacity(points.len()));
+    for (j, (xj, _)) in points.iter().enumerate() {
+        let (mut num, mut den) = (F::one(), F::one());
+        for (m, (xm, _)) in points.iter().enumerate() {
+            if m != j {
+                num = num * (x - *xm);
+                den = den * (*xj - *xm);
+            }
+        }
+        nums.push(num);
+        dens.push(den);
+    }
+    // prefix[j] = dens[0] ⋯ dens[j-1]; walk back from 1 / (dens[0] ⋯ dens[n-1]).
+    let mut prefix = Vec::with_capacity(dens.len());
+    let mut all = F::one();
+    for d in &dens {
+        prefix.push(all);
+        all = all * *d;
+    }
+    let mut inv = all.invert()?;
+    let mut acc = F::zero();
+    for j in (0..points.len()).rev() {
+        acc = acc + points[j].1 * nums[j] * (inv * prefix[j]);
+        inv = inv * dens[j];
+    }
+    Some(acc)
+}
@@ -16,6 +16,32 @@ pub trait RandomOracle {
 
     /// A regular hash you can truncate for `H_b` (Fischlin predicate).
     fn H(&mut self, label: &'static str, data: &[u8]) -> Vec<u8>;
+
+    /// Variable-length output (FS challenges). The requested length is part of the input, so
+    /// a 64-byte output is never an extension of the 32-byte one for the same transcript.
+    /// Default: counter mode over `H_full`, block_i = H_full(label, "fsr/H_xof" || len_le64 || i_le32 || data).
+    /// Backends with a native XOF override this.
+    #[allow(non_snake_case)] // named after `H` / `H_full`
+    fn H_xof(&mut self, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+        let mut input = Vec::with_capacity(data.len() + 21);
+        input.extend_from_slice(b"fsr/H_xof");
+        input.extend_from_slice(&(out_len as u64).to_le_bytes());
+        let ctr_at = input.len();
+        input.extend_from_slice(&0u32.to_le_bytes());
+        input.extend_from_slice(data);
+
+        let mut out = Vec::with_capacity(out_len);
+        let mut ctr: u32 = 0;
+        while out.len() < out_len {
+            input[ctr_at..ctr_at + 4].copy_from_slice(&ctr.to_le_bytes());
+            let block = self.H_full(label, &input);
+            assert!(!block.is_empty(), "H_full returned no bytes");
+            out.extend_from_slice(&block);
+            ctr += 1;
+        }
+        out.truncate(out_len);
+        out
+    }
 }
 
 /// Helper: truncate a hash to `b` bits, return as `u64` (supports b ≤ 56 here).
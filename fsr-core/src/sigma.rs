@@ -0,0 +1,331 @@
+//! Sigma protocols as a trait, and generic FS / Fischlin drivers over it.
+//!
+//! A `SigmaProtocol` works on typed values; the drivers own all byte handling. They run the same
+//! transcripts as `prove!` / `verify!` (FS: `mode`, `x`, `sid`, `m_i`, then `e_i` of
+//! `<P::Challenge as Challenge>::BYTES`; Fischlin: `FischlinOracle` with a search over small
+//! integer challenges), so a proof from `prove_fs::<P>` verifies under `verify!` with
+//! `challenge_len = <P::Challenge as Challenge>::BYTES` and vice versa.
+//!
+//! Commitments and responses are decoded strictly: a proof verifies only if every `m_i` / `z_i`
+//! is exactly one canonical encoding.
+//!
+//! Besides `m_i` (the commitment as a whole), every component commitment is bound under its own
+//! label path before the challenge: `P::bind` absorbs them through a `Binder`, and the drivers
+//! check that the bound paths are exactly `P::require`'s, the runtime counterpart of the DSL's
+//! `bind` / `require`. A plain protocol has one component at the root path; combinators such as
+//! `protocols::and::And` scope their branches (`and.0`, `and.1`, nested as `and.1/and.0`), so a
+//! composition that forgets to bind a branch is refused by the prover and rejected by the
+//! verifier. With `verify!`, pass a `bind` hook that calls `bind_commitment::<P>`.
+
+use rand_core::RngCore;
+
+use crate::error::ProveError;
+use crate::fischlin::{FischlinOracle, FischlinParams};
+use crate::fischlin_proof::{self, FischlinProof};
+use crate::fs_proof::FsProof;
+use crate::framing::{self, op};
+use crate::fs_runtime::FSOracle;
+use crate::groups::PrimeField;
+use crate::runtime::{RandomOracle, TranscriptRuntime};
+use crate::{CanonicalDecode, CanonicalEncode, Challenge, Result};
+
+/// A three-move public-coin proof of knowledge (a, e, z) for a relation between `Statement` and
+/// `Witness`, special sound for `SPECIAL_SOUNDNESS` transcripts and special honest-verifier ZK.
+///
+/// Challenges are scalar-field elements: the FS driver reduces `Challenge::BYTES` oracle bytes,
+/// and Fischlin's small integer challenges are mapped with `PrimeField::from_u64`.
+pub trait SigmaProtocol {
+    type Statement: CanonicalEncode;
+    type Witness;
+    type Commitment: Clone + CanonicalEncode + CanonicalDecode;
+    type Challenge: PrimeField;
+    type Response: Clone + CanonicalEncode + CanonicalDecode;
+    /// Prover randomness kept between `commit` and `respond`.
+    type State;
+
+    /// Number of accepting transcripts with one commitment and distinct challenges that
+    /// `extract` needs.
+    const SPECIAL_SOUNDNESS: usize = 2;
+
+    fn commit<R: RngCore + ?Sized>(x: &Self::Statement, w: &Self::Witness, rng: &mut R) -> (Self::Commitment, Self::State);
+
+    fn respond(x: &Self::Statement, w: &Self::Witness, state: &Self::State, e: &Self::Challenge) -> Self::Response;
+
+    fn verify(x: &Self::Statement, a: &Self::Commitment, e: &Self::Challenge, z: &Self::Response) -> bool;
+
+    /// An accepting (a, z) for a given challenge, distributed as in honest runs.
+    fn simulate<R: RngCore + ?Sized>(x: &Self::Statement, e: &Self::Challenge, rng: &mut R) -> (Self::Commitment, Self::Response);
+
+    /// The witness from `SPECIAL_SOUNDNESS` accepting transcripts sharing commitment `a`, with
+    /// pairwise distinct challenges; `None` if the transcripts do not qualify.
+    fn extract(x: &Self::Statement, a: &Self::Commitment, transcripts: &[(Self::Challenge, Self::Response)]) -> Option<Self::Witness>;
+
+    /// Label paths of the component commitments that must be bound before the challenge.
+    /// Default: the commitment itself, at the current path.
+    fn require(x: &Self::Statement, labels: &mut Labels) {
+        let _ = x;
+        labels.require();
+    }
+
+    /// Bind commitment `a`, one `Binder::bind` per path listed by `require`, in the same order.
+    fn bind(x: &Self::Statement, a: &Self::Commitment, binder: &mut Binder<'_>) {
+        let _ = x;
+        binder.bind(a);
+    }
+}
+
+/// An ordered list of label paths. A path is the `/`-joined scopes opened around a component
+/// commitment (`""` at the root, `and.1/and.0` for the first branch of an `And` nested as the
+/// second branch of another).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Labels {
+    path: String,
+    labels: Vec<String>,
+}
+
+impl Labels {
+    pub fn new() -> Self { Self::default() }
+
+    /// Record the current path.
+    pub fn require(&mut self) { self.labels.push(self.path.clone()); }
+
+    /// Run `f` with `label` appended to the current path.
+    pub fn scope<T>(&mut self, label: &str, f: impl FnOnce(&mut Self) -> T) -> T {
+        let len = self.enter(label);
+        let out = f(self);
+        self.path.truncate(len);
+        out
+    }
+
+    pub fn as_slice(&self) -> &[String] { &self.labels }
+
+    fn enter(&mut self, label: &str) -> usize {
+        let len = self.path.len();
+        if len > 0 { self.path.push('/'); }
+        self.path.push_str(label);
+        len
+    }
+}
+
+/// Absorbs component commitments into a transcript under their label paths, recording the
+/// paths bound. Each bind is one `sigma.commit` absorb of `frame(path, encoding)`.
+pub struct Binder<'a> {
+    oracle: &'a mut dyn TranscriptRuntime,
+    bound: Labels,
+}
+
+impl<'a> Binder<'a> {
+    pub fn new(oracle: &'a mut dyn TranscriptRuntime) -> Self {
+        Self { oracle, bound: Labels::new() }
+    }
+
+    /// Bind `a` at the current path.
+    pub fn bind<T: CanonicalEncode + ?Sized>(&mut self, a: &T) {
+        let data = framing::framed(op::ABSORB, self.bound.path.as_bytes(), &encode(a));
+        self.oracle.absorb("sigma.commit", &data);
+        self.bound.require();
+    }
+
+    /// Run `f` with `label` appended to the current path.
+    pub fn scope<T>(&mut self, label: &str, f: impl FnOnce(&mut Self) -> T) -> T {
+        let len = self.bound.enter(label);
+        let out = f(self);
+        self.bound.path.truncate(len);
+        out
+    }
+
+    /// Paths bound so far, in order.
+    pub fn bound(&self) -> &[String] { self.bound.as_slice() }
+}
+
+/// Bind `a` into `oracle` with `P::bind`, and check that exactly `P::require`'s paths were bound
+/// (same paths, same order).
+pub fn bind_commitment<P: SigmaProtocol>(oracle: &mut dyn TranscriptRuntime, x: &P::Statement, a: &P::Commitment) -> Result<()> {
+    let mut required = Labels::new();
+    P::require(x, &mut required);
+    let mut binder = Binder::new(oracle);
+    P::bind(x, a, &mut binder);
+    if binder.bound() != required.as_slice() {
+        return Err(ProveError::Malformed("sigma: bound commitments do not match the required labels"));
+    }
+    Ok(())
+}
+
+/// Fischlin's transcript runtime only records coverage (the hash covers `m_i`), so the Fischlin
+/// verifier checks bindings against a sink.
+struct Discard;
+impl TranscriptRuntime for Discard {
+    fn absorb(&mut self, _label: &'static str, _bytes: &[u8]) {}
+}
+
+/// `v`'s canonical encoding (the drivers bind a statement as `x = encode(statement)`).
+pub fn encode<T: CanonicalEncode + ?Sized>(v: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    v.encode(&mut out);
+    out
+}
+
+/// Decode `bytes` as exactly one `T` (no trailing bytes).
+pub fn decode_exact<T: CanonicalDecode>(mut bytes: &[u8]) -> Option<T> {
+    let v = T::decode(&mut bytes)?;
+    bytes.is_empty().then_some(v)
+}
+
+/// Fischlin's challenge for repetition i: a little-endian integer below 2^t, as `search_round`
+/// enumerates them.
+pub(crate) fn fischlin_challenge<F: PrimeField>(params: &FischlinParams, e: &[u8]) -> Option<F> {
+    let t = params.t.min(56) as usize;
+    if e.len() != t.div_ceil(8).max(1) { return None; }
+    let v = e.iter().rev().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+    (v >> t == 0).then(|| F::from_u64(v))
+}
+
+/// FS proof (one repetition) under `FSOracle::new(ro)`.
+pub fn prove_fs<P: SigmaProtocol, RO: RandomOracle, R: RngCore + ?Sized>(
+    ro: RO,
+    x: &P::Statement,
+    w: &P::Witness,
+    sid: &[u8],
+    rng: &mut R,
+) -> Result<FsProof> {
+    let mut oracle = FSOracle::new(ro);
+    oracle.absorb("mode", b"FS");
+    oracle.absorb("x", &encode(x));
+    oracle.absorb("sid", sid);
+
+    let (a, state) = P::commit(x, w, rng);
+    let m = encode(&a);
+    oracle.absorb("m_i", &m);
+    bind_commitment::<P>(&mut oracle, x, &a)?;
+
+    let e_bytes = oracle.derive_challenge("e_i", &[], <P::Challenge as Challenge>::BYTES);
+    let e = <P::Challenge as Challenge>::from_oracle_bytes("e_i", &e_bytes);
+    let z = encode(&P::respond(x, w, &state, &e));
+    oracle.absorb("e_i", &e_bytes);
+    oracle.absorb("z_i", &z);
+
+    Ok(FsProof { m: vec![m], z: vec![z], rho: 1, b: 0 })
+}
+
+pub fn verify_fs<P: SigmaProtocol, RO: RandomOracle>(ro: RO, x: &P::Statement, sid: &[u8], proof: &FsProof) -> bool {
+    let Some(rounds) = fs_challenges::<P, RO>(ro, x, sid, proof) else { return false };
+    rounds.iter().zip(&proof.z).all(|((a, e), z)| fs_round::<P>(x, a, e, z))
+}
+
+/// Rounds of an FS proof as `verify_fs` sees them: (a_i, e_i bytes) per round.
+type FsRounds<P> = Vec<(<P as SigmaProtocol>::Commitment, Vec<u8>)>;
+
+/// The (a_i, e_i) of an FS proof: every `m_i` decoded and bound into the oracle, then the
+/// challenge bytes re-derived as `verify_fs` does; `None` if the proof is malformed or some
+/// `m_i` does not decode exactly or binds the wrong paths.
+fn fs_challenges<P: SigmaProtocol, RO: RandomOracle>(ro: RO, x: &P::Statement, sid: &[u8], proof: &FsProof) -> Option<FsRounds<P>> {
+    if !proof.is_well_formed() { return None; }
+    let mut oracle = FSOracle::new(ro);
+    oracle.absorb("mode", b"FS");
+    oracle.absorb("x", &encode(x));
+    oracle.absorb("sid", sid);
+    let mut commitments = Vec::with_capacity(proof.m.len());
+    for m in &proof.m {
+        oracle.absorb("m_i", m);
+        let a = decode_exact::<P::Commitment>(m)?;
+        bind_commitment::<P>(&mut oracle, x, &a).ok()?;
+        commitments.push(a);
+    }
+
+    let mut rounds = Vec::with_capacity(proof.m.len());
+    for (a, z_bytes) in commitments.into_iter().zip(&proof.z) {
+        let e_bytes = oracle.derive_challenge("e_i", &[], <P::Challenge as Challenge>::BYTES);
+        oracle.absorb("e_i", &e_bytes);
+        oracle.absorb("z_i", z_bytes);
+        rounds.push((a, e_bytes));
+    }
+    Some(rounds)
+}
+
+/// Decoded (a_i, e_i, z_i) triples.
+pub(crate) type Transcripts<P> = Vec<(<P as SigmaProtocol>::Commitment, <P as SigmaProtocol>::Challenge, <P as SigmaProtocol>::Response)>;
+
+/// The (a_i, e_i, z_i) of an FS proof, with every challenge re-derived as `verify_fs` does; `None`
+/// if the proof is malformed, some `m_i` / `z_i` does not decode exactly, or a binding is off.
+pub(crate) fn fs_transcripts<P: SigmaProtocol, RO: RandomOracle>(ro: RO, x: &P::Statement, sid: &[u8], proof: &FsProof) -> Option<Transcripts<P>> {
+    fs_challenges::<P, RO>(ro, x, sid, proof)?
+        .into_iter()
+        .zip(&proof.z)
+        .map(|((a, e), z)| Some((a, <P::Challenge as Challenge>::from_oracle_bytes("e_i", &e), decode_exact::<P::Response>(z)?)))
+        .collect()
+}
+
+/// One FS round with a decoded, already bound commitment: z_i decodes exactly and `P::verify`
+/// accepts on the challenge reduced from the bytes e_i.
+fn fs_round<P: SigmaProtocol>(x: &P::Statement, a: &P::Commitment, e: &[u8], z: &[u8]) -> bool {
+    let e = <P::Challenge as Challenge>::from_oracle_bytes("e_i", e);
+    decode_exact::<P::Response>(z).is_some_and(|z| P::verify(x, a, &e, &z))
+}
+
+/// `verify_fs`'s checks of round i on its bytes (m_i, e_i, z_i), with e_i the oracle bytes the
+/// challenge is reduced from: exact decoding, the bindings `P::require` asks for (which
+/// `verify_fs` checks while re-deriving the challenges), `P::verify`.
+pub fn fs_sigma_verify<P: SigmaProtocol>(x: &P::Statement) -> impl Fn(usize, &[u8], &[u8], &[u8]) -> bool + '_ {
+    move |_i, m, e, z| match decode_exact::<P::Commitment>(m) {
+        Some(a) => bind_commitment::<P>(&mut Discard, x, &a).is_ok() && fs_round::<P>(x, &a, e, z),
+        None => false,
+    }
+}
+
+/// `verify_fischlin`'s check of round i on its bytes (m_i, e_i, z_i), with e_i the t-bit
+/// little-endian challenge: as `fs_sigma_verify`, and e_i must be in range.
+pub fn fischlin_sigma_verify<P: SigmaProtocol>(params: FischlinParams, x: &P::Statement) -> impl Fn(usize, &[u8], &[u8], &[u8]) -> bool + '_ {
+    move |_i, m, e, z| {
+        match (decode_exact::<P::Commitment>(m), fischlin_challenge::<P::Challenge>(&params, e), decode_exact::<P::Response>(z)) {
+            (Some(a), Some(e), Some(z)) => bind_commitment::<P>(&mut Discard, x, &a).is_ok() && P::verify(x, &a, &e, &z),
+            _ => false,
+        }
+    }
+}
+
+/// Fischlin proof with `params.rho` repetitions under `FischlinOracle::new(ro, params)`.
+pub fn prove_fischlin<P: SigmaProtocol, RO: RandomOracle, R: RngCore + ?Sized>(
+    ro: RO,
+    params: FischlinParams,
+    x: &P::Statement,
+    w: &P::Witness,
+    sid: &[u8],
+    rng: &mut R,
+) -> Result<FischlinProof> {
+    let mut oracle = FischlinOracle::new(ro, params);
+    oracle.begin(&encode(x), sid);
+
+    let rho = params.rho as usize;
+    let mut m = Vec::with_capacity(rho);
+    let mut states = Vec::with_capacity(rho);
+    for _ in 0..rho {
+        let (a, state) = P::commit(x, w, rng);
+        let a_bytes = encode(&a);
+        oracle.push_first_message(&a_bytes)?;
+        bind_commitment::<P>(&mut oracle, x, &a)?;
+        m.push(a_bytes);
+        states.push(state);
+    }
+    oracle.seal_first_messages()?;
+
+    let (mut e, mut z) = (Vec::with_capacity(rho), Vec::with_capacity(rho));
+    for (i, state) in states.iter().enumerate() {
+        let (e_i, z_i) = oracle.search_round(i as u32, |e_try| {
+            let e = fischlin_challenge::<P::Challenge>(&params, e_try).expect("search_round enumerates e < 2^t");
+            encode(&P::respond(x, w, state, &e))
+        })?;
+        e.push(e_i);
+        z.push(z_i);
+    }
+    Ok(FischlinProof { m, e, z, b: params.b, rho: params.rho })
+}
+
+pub fn verify_fischlin<P: SigmaProtocol, RO: RandomOracle>(
+    ro: RO,
+    params: FischlinParams,
+    x: &P::Statement,
+    sid: &[u8],
+    proof: &FischlinProof,
+) -> bool {
+    fischlin_proof::verify_fischlin(ro, params, &encode(x), sid, proof, fischlin_sigma_verify::<P>(params, x))
+}
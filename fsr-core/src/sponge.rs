@@ -0,0 +1,187 @@
+//! Duplex-sponge transcript backend over Keccak-f[1600] (feature = "sponge").
+//!
+//! `KeccakSponge` is a SAFE-style duplex: `absorb` / `squeeze` / `ratchet` on a 1600-bit state
+//! with a 136-byte rate (256-bit capacity security). `SpongeOracle` layers labelled, framed
+//! operations on top and implements `TranscriptRuntime`, `Absorb`, `Oracle` and `RandomOracle`.
+//!
+//! Unlike `FSOracle` / `HashOracle`, nothing is buffered: each absorb is folded into the state
+//! immediately, so a challenge costs O(out_len) regardless of the transcript length. After every
+//! challenge the state is ratcheted, so two successive challenges are independent even if nothing
+//! was absorbed between them (and earlier state cannot be recovered from a leaked later state).
+
+use crate::framing::{self, op};
+use crate::runtime::{RandomOracle, TranscriptRuntime};
+use crate::{Absorb, CanonicalEncode, Challenge, Oracle, Result};
+
+const STATE_BYTES: usize = 200;
+/// Rate in bytes (capacity = 64 bytes).
+pub const RATE: usize = 136;
+
+// Output length of `H_full` / `H`.
+const RO_BYTES: usize = 32;
+
+/// Raw Keccak-f[1600] duplex sponge.
+#[derive(Clone)]
+pub struct KeccakSponge {
+    state: [u64; 25],
+    pos: usize,
+    squeezing: bool,
+}
+
+impl KeccakSponge {
+    /// Start a sponge whose initial state is bound to `domain` (the SAFE "tag"), written into the
+    /// capacity so it can never collide with absorbed data.
+    pub fn new(domain: &[u8]) -> Self {
+        let mut tag = KeccakSponge { state: [0u64; 25], pos: 0, squeezing: false };
+        tag.absorb(b"YavOracle/sponge/v1");
+        tag.absorb(&(domain.len() as u64).to_le_bytes());
+        tag.absorb(domain);
+        let mut iv = [0u8; STATE_BYTES - RATE];
+        tag.squeeze(&mut iv);
+
+        let mut s = KeccakSponge { state: [0u64; 25], pos: 0, squeezing: false };
+        for (i, b) in iv.iter().enumerate() {
+            s.xor_byte(RATE + i, *b);
+        }
+        s.permute();
+        s
+    }
+
+    #[inline]
+    fn xor_byte(&mut self, i: usize, b: u8) {
+        self.state[i / 8] ^= (b as u64) << (8 * (i % 8));
+    }
+
+    #[inline]
+    fn byte(&self, i: usize) -> u8 {
+        (self.state[i / 8] >> (8 * (i % 8))) as u8
+    }
+
+    fn permute(&mut self) {
+        keccak::f1600(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Absorb bytes into the rate. Absorbing after a squeeze starts a fresh block.
+    pub fn absorb(&mut self, bytes: &[u8]) {
+        if self.squeezing {
+            self.permute();
+            self.squeezing = false;
+        }
+        for &b in bytes {
+            self.xor_byte(self.pos, b);
+            self.pos += 1;
+            if self.pos == RATE {
+                self.permute();
+            }
+        }
+    }
+
+    /// Squeeze `out.len()` bytes. The first squeeze after absorbing pads (10*1) and permutes.
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        if !self.squeezing {
+            self.xor_byte(self.pos, 0x01);
+            self.xor_byte(RATE - 1, 0x80);
+            self.permute();
+            self.squeezing = true;
+        }
+        for o in out.iter_mut() {
+            if self.pos == RATE {
+                self.permute();
+            }
+            *o = self.byte(self.pos);
+            self.pos += 1;
+        }
+    }
+
+    /// Permute and zero the rate: the next operation starts from a state that depends on
+    /// everything so far but from which the previous state cannot be recomputed.
+    pub fn ratchet(&mut self) {
+        keccak::f1600(&mut self.state);
+        for w in self.state.iter_mut().take(RATE / 8) {
+            *w = 0;
+        }
+        self.pos = 0;
+        self.squeezing = false;
+    }
+}
+
+/// Labelled transcript over a `KeccakSponge`.
+///
+/// Every operation enters the sponge as a `framing` frame (`op || len(label) || label ||
+/// len(data) || data`), so distinct operation sequences never produce the same sponge input.
+#[derive(Clone)]
+pub struct SpongeOracle {
+    sponge: KeccakSponge,
+}
+
+impl SpongeOracle {
+    pub fn new(domain: &'static [u8]) -> Self {
+        Self { sponge: KeccakSponge::new(domain) }
+    }
+
+    fn frame(sponge: &mut KeccakSponge, op: u8, label: &str, data: &[u8]) {
+        framing::write_frame(|b| sponge.absorb(b), op, label.as_bytes(), data);
+    }
+
+    /// Squeeze `out_len` challenge bytes bound to everything absorbed so far, then ratchet.
+    pub fn squeeze_challenge(&mut self, label: &'static str, out_len: usize) -> Vec<u8> {
+        Self::frame(&mut self.sponge, op::CHALLENGE, label, &(out_len as u64).to_le_bytes());
+        let mut out = vec![0u8; out_len];
+        self.sponge.squeeze(&mut out);
+        self.sponge.ratchet();
+        out
+    }
+
+    /// Same shape as `FSOracle::derive_challenge`; `extra` is absorbed first.
+    pub fn derive_challenge(&mut self, label: &'static str, extra: &[u8], out_len: usize) -> Vec<u8> {
+        if !extra.is_empty() {
+            Self::frame(&mut self.sponge, op::EXTRA, "extra", extra);
+        }
+        self.squeeze_challenge(label, out_len)
+    }
+
+    /// Stateless hash of `(transcript so far, label, data)`; does not advance the transcript.
+    fn fork_hash(&self, op: u8, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+        let mut s = self.sponge.clone();
+        Self::frame(&mut s, op, label, data);
+        let mut out = vec![0u8; out_len];
+        s.squeeze(&mut out);
+        out
+    }
+}
+
+impl TranscriptRuntime for SpongeOracle {
+    fn absorb(&mut self, label: &'static str, bytes: &[u8]) {
+        Self::frame(&mut self.sponge, op::ABSORB, label, bytes);
+    }
+}
+
+impl Absorb for SpongeOracle {
+    fn absorb_bytes(&mut self, label: &'static str, bytes: &[u8]) {
+        Self::frame(&mut self.sponge, op::ABSORB, label, bytes);
+    }
+}
+
+impl Oracle for SpongeOracle {
+    fn challenge<C: Challenge + CanonicalEncode>(&mut self, label: &'static str) -> Result<C> {
+        let out = self.squeeze_challenge(label, C::BYTES);
+        Ok(C::from_oracle_bytes(label, &out))
+    }
+}
+
+impl RandomOracle for SpongeOracle {
+    fn H_full(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+        self.fork_hash(op::H_FULL, label, data, RO_BYTES)
+    }
+
+    fn H(&mut self, label: &'static str, data: &[u8]) -> Vec<u8> {
+        self.fork_hash(op::H, label, data, RO_BYTES)
+    }
+
+    fn H_xof(&mut self, label: &'static str, data: &[u8], out_len: usize) -> Vec<u8> {
+        let mut input = (out_len as u64).to_le_bytes().to_vec();
+        input.extend_from_slice(data);
+        self.fork_hash(op::H_XOF, label, &input, out_len)
+    }
+}
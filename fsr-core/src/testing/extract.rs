@@ -0,0 +1,164 @@
+//! Special-soundness extractor harness: is a `SigmaProtocol` really a proof of knowledge?
+//!
+//! `check` runs a prover closure n times (n = `FischlinParams::n_special`) against a
+//! `Rewind` oracle. Every run sees the same oracle up to the fork challenge, so a prover with a
+//! fixed random tape (the closure seeds its own rng) sends the same first message each time,
+//! and the fork challenge is programmed to a fresh, distinct value per run. The n transcripts
+//! (a, e_i, z_i) must all accept with one shared a; `P::extract`, given the first
+//! `P::SPECIAL_SOUNDNESS` of them, then has to return a witness that satisfies the caller's
+//! relation. A response that does not bind the witness (z
+//! independent of w, an `extract` that ignores its input, a combinator that drops a branch)
+//! fails the last step with `Failure::WrongWitness`.
+//!
+//! `Rewind` is an `Oracle` (also through `&mut`), so closures can run the typed-`Transcript`
+//! provers (`dleq::prove`, `linear::prove`) directly. Challenges other than the fork come from
+//! a `HashOracle` over the run's transcript, so earlier challenges replay and later ones depend
+//! on the programmed answer.
+
+use rand_core::RngCore;
+
+use crate::error::ProveError;
+use crate::fischlin::FischlinParams;
+use crate::sigma::{encode, SigmaProtocol};
+use crate::{Absorb, CanonicalEncode, Challenge, HashOracle, Oracle, RecEvent, Result};
+
+/// Oracle bytes drawn per run for the fork challenge; `Challenge::BYTES` must not exceed it.
+pub const ANSWER_BYTES: usize = 128;
+
+/// Fork challenges drawn per run before giving up on one distinct from the earlier runs'.
+pub const MAX_REDRAWS: usize = 64;
+
+/// Why `check` failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// `n_special` is below the protocol's `SPECIAL_SOUNDNESS`: Fischlin parameters set this way
+    /// count on an extractor that does not exist.
+    TooFewTranscripts { n_special: usize, needed: usize },
+    /// Run `run` of the prover returned an error.
+    Prover { run: usize, error: ProveError },
+    /// Run `run` never asked for the fork challenge.
+    NoFork { run: usize },
+    /// Run `run` absorbed something else before the fork than run 0 (the prover is not
+    /// deterministic given its tape).
+    Diverged { run: usize },
+    /// Run `run` answered with another first message than run 0.
+    CommitmentChanged { run: usize },
+    /// Run `run`'s transcript does not verify.
+    Rejected { run: usize },
+    /// `MAX_REDRAWS` fork challenges for run `run` all repeated an earlier run's: the challenge
+    /// space is too small for `n_special` distinct challenges (or `from_oracle_bytes` ignores
+    /// its input).
+    ChallengeSpaceTooSmall { run: usize },
+    /// `extract` returned `None` on accepting transcripts.
+    NoWitness,
+    /// `extract` returned a witness outside the relation.
+    WrongWitness,
+}
+
+/// An oracle programmed at one challenge: challenge number `fork` (0-based, in call order)
+/// is `C::from_oracle_bytes(label, answer)`, every other one comes from a `HashOracle` over the
+/// absorbed transcript. Records the events before the fork.
+pub struct Rewind {
+    inner: HashOracle,
+    fork: usize,
+    answer: Vec<u8>,
+    seen: usize,
+    prefix: Vec<RecEvent>,
+    forked: Option<&'static str>,
+}
+
+impl Rewind {
+    pub fn new(fork: usize, answer: Vec<u8>) -> Self {
+        Self { inner: HashOracle::new(b"fsr/testing/rewind"), fork, answer, seen: 0, prefix: Vec::new(), forked: None }
+    }
+
+    /// The events absorbed and challenges drawn before the fork.
+    pub fn prefix(&self) -> &[RecEvent] { &self.prefix }
+
+    /// The fork challenge's label, once it has been drawn.
+    pub fn forked(&self) -> Option<&'static str> { self.forked }
+}
+
+impl Absorb for Rewind {
+    fn absorb_bytes(&mut self, label: &'static str, bytes: &[u8]) {
+        if self.seen <= self.fork {
+            self.prefix.push(RecEvent::Absorb { label, bytes: bytes.to_vec() });
+        }
+        self.inner.absorb_bytes(label, bytes);
+    }
+}
+
+impl Oracle for Rewind {
+    fn challenge<C: Challenge + CanonicalEncode>(&mut self, label: &'static str) -> Result<C> {
+        let i = self.seen;
+        let c = if i == self.fork {
+            let answer = self.answer.get(..C::BYTES).ok_or(ProveError::Malformed("rewind: challenge wider than the programmed answer"))?;
+            self.forked = Some(label);
+            C::from_oracle_bytes(label, answer)
+        } else {
+            self.inner.challenge::<C>(label)?
+        };
+        let bytes = encode(&c);
+        if i < self.fork {
+            self.prefix.push(RecEvent::Challenge { label, bytes: bytes.clone() });
+        }
+        // Later challenges depend on this one (in particular on the programmed answer).
+        self.inner.absorb_bytes(label, &bytes);
+        self.seen += 1;
+        Ok(c)
+    }
+}
+
+/// Rewind `prover` at challenge number `fork` until `params.n_special` accepting transcripts
+/// of `P` share a first message, extract, and check `relation(x, w)`. `prover` must use the same
+/// random tape on every call and return its (a, z); `rng` draws the fork challenges.
+pub fn check<P, F, R>(
+    params: &FischlinParams,
+    x: &P::Statement,
+    relation: impl Fn(&P::Statement, &P::Witness) -> bool,
+    fork: usize,
+    mut prover: F,
+    rng: &mut R,
+) -> core::result::Result<P::Witness, Failure>
+where
+    P: SigmaProtocol,
+    F: FnMut(&mut Rewind) -> Result<(P::Commitment, P::Response)>,
+    R: RngCore + ?Sized,
+{
+    let n = params.n_special as usize;
+    if n < P::SPECIAL_SOUNDNESS {
+        return Err(Failure::TooFewTranscripts { n_special: n, needed: P::SPECIAL_SOUNDNESS });
+    }
+
+    let mut first: Option<(Vec<RecEvent>, P::Commitment, Vec<u8>)> = None;
+    let mut transcripts: Vec<(P::Challenge, P::Response)> = Vec::with_capacity(n);
+    for run in 0..n {
+        // Rerun on the (negligible, for a real challenge space) chance that the fork challenge
+        // repeats an earlier run's.
+        let mut redraws = 0;
+        let (oracle, a, z, e) = loop {
+            if redraws == MAX_REDRAWS { return Err(Failure::ChallengeSpaceTooSmall { run }); }
+            redraws += 1;
+            let mut answer = vec![0u8; ANSWER_BYTES];
+            rng.fill_bytes(&mut answer);
+            let mut oracle = Rewind::new(fork, answer);
+            let (a, z) = prover(&mut oracle).map_err(|error| Failure::Prover { run, error })?;
+            let Some(label) = oracle.forked else { return Err(Failure::NoFork { run }) };
+            let e = <P::Challenge as Challenge>::from_oracle_bytes(label, &oracle.answer[..<P::Challenge as Challenge>::BYTES]);
+            if transcripts.iter().all(|(f, _)| *f != e) { break (oracle, a, z, e); }
+        };
+        if !P::verify(x, &a, &e, &z) { return Err(Failure::Rejected { run }); }
+        match &first {
+            None => first = Some((oracle.prefix, a.clone(), encode(&a))),
+            Some((prefix, _, a0)) => {
+                if oracle.prefix != *prefix { return Err(Failure::Diverged { run }); }
+                if encode(&a) != *a0 { return Err(Failure::CommitmentChanged { run }); }
+            }
+        }
+        transcripts.push((e, z));
+    }
+
+    let (_, a, _) = first.expect("n_special >= 2 runs");
+    let w = P::extract(x, &a, &transcripts[..P::SPECIAL_SOUNDNESS]).ok_or(Failure::NoWitness)?;
+    if relation(x, &w) { Ok(w) } else { Err(Failure::WrongWitness) }
+}
@@ -0,0 +1,237 @@
+//! Honest-verifier zero-knowledge harness: do `P::simulate`'s transcripts look like honest ones?
+//!
+//! Coverage checks say nothing about zero knowledge, and a wrong simulator is easy to miss: `Or`
+//! runs its branch simulators inside every honest proof, so a biased `simulate` leaks which
+//! branch the prover knows. This module pairs the honest prover (`commit`, a challenge,
+//! `respond`) with the simulator (the challenge, then `simulate`) and compares the transcripts:
+//! * `sample` draws both distributions and runs a two-sample chi-square test; meaningful when
+//!   the transcripts can repeat, i.e. over `testing::toy` with a few samples per transcript,
+//! * `exact` lists every coin sequence of both over `Toy<Q>` for one challenge (special HVZK),
+//!   so the two distributions must be equal, transcript by transcript.
+//!
+//! Every transcript, honest or simulated, also goes through the per-round check of the chosen
+//! driver (`sigma::fs_sigma_verify`, the round checks of `verify_fs`, or
+//! `sigma::fischlin_sigma_verify`, the closure `verify_fischlin` runs), so a simulator whose
+//! output does not verify, decode or bind is caught on any backend.
+
+use std::collections::HashMap;
+
+use rand_core::RngCore;
+
+use crate::fischlin::FischlinParams;
+use crate::groups::PrimeField;
+use crate::sigma::{encode, fischlin_challenge, fischlin_sigma_verify, fs_sigma_verify, SigmaProtocol};
+use crate::testing::toy::Zq;
+use crate::Challenge;
+
+/// Standard deviations the chi-square statistic may sit above its mean (a false alarm about once
+/// in 30 000 honest runs).
+pub const Z: f64 = 4.0;
+
+/// Most coin sequences `exact` enumerates per side.
+pub const MAX_TAPES: u64 = 1 << 22;
+
+/// A transcript as the driver sees it: (m, e, z) bytes.
+pub type Bytes = (Vec<u8>, Vec<u8>, Vec<u8>);
+
+/// Whose challenges and per-round check the transcripts use.
+#[derive(Clone, Copy, Debug)]
+pub enum Driver {
+    /// `Challenge::BYTES` oracle bytes, reduced; checked by `fs_sigma_verify`.
+    Fs,
+    /// A t-bit integer; checked by `fischlin_sigma_verify`.
+    Fischlin(FischlinParams),
+}
+
+impl Driver {
+    /// A uniform challenge in the driver's encoding.
+    pub fn draw<C: Challenge, R: RngCore + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
+        match self {
+            Driver::Fs => {
+                let mut e = vec![0u8; C::BYTES];
+                rng.fill_bytes(&mut e);
+                e
+            }
+            Driver::Fischlin(params) => {
+                let t = params.t.min(56) as usize;
+                let v = rng.next_u64() & ((1u64 << t) - 1);
+                v.to_le_bytes()[..t.div_ceil(8).max(1)].to_vec()
+            }
+        }
+    }
+
+    /// The challenge `P` sees for the bytes e; `None` if e is not a valid encoding.
+    pub fn challenge<C: PrimeField>(&self, e: &[u8]) -> Option<C> {
+        match self {
+            Driver::Fs => Some(C::from_oracle_bytes("e_i", e)),
+            Driver::Fischlin(params) => fischlin_challenge(params, e),
+        }
+    }
+
+    /// The driver's verifier check of one round.
+    pub fn accepts<P: SigmaProtocol>(&self, x: &P::Statement, (m, e, z): &Bytes) -> bool {
+        match self {
+            Driver::Fs => fs_sigma_verify::<P>(x)(0, m, e, z),
+            Driver::Fischlin(params) => fischlin_sigma_verify::<P>(*params, x)(0, m, e, z),
+        }
+    }
+}
+
+/// An honest transcript on challenge e: `commit`, then `respond`.
+pub fn honest<P: SigmaProtocol, R: RngCore + ?Sized>(driver: &Driver, x: &P::Statement, w: &P::Witness, e: &[u8], rng: &mut R) -> Option<Bytes> {
+    let c = driver.challenge::<P::Challenge>(e)?;
+    let (a, state) = P::commit(x, w, rng);
+    Some((encode(&a), e.to_vec(), encode(&P::respond(x, w, &state, &c))))
+}
+
+/// A simulated transcript on challenge e.
+pub fn simulated<P: SigmaProtocol, R: RngCore + ?Sized>(driver: &Driver, x: &P::Statement, e: &[u8], rng: &mut R) -> Option<Bytes> {
+    let c = driver.challenge::<P::Challenge>(e)?;
+    let (a, z) = P::simulate(x, &c, rng);
+    Some((encode(&a), e.to_vec(), encode(&z)))
+}
+
+/// What `sample` measured.
+#[derive(Clone, Debug)]
+pub struct Report {
+    /// Transcripts drawn per side.
+    pub samples: usize,
+    /// Distinct transcripts seen on either side.
+    pub cells: usize,
+    /// Honest / simulated transcripts the driver's check rejected.
+    pub rejected: [usize; 2],
+    /// Σ (h − s)² / (h + s) over the cells, for h honest and s simulated counts.
+    pub chi2: f64,
+}
+
+impl Report {
+    /// Degrees of freedom of `chi2`.
+    pub fn df(&self) -> usize { self.cells.saturating_sub(1) }
+
+    /// The chi-square quantile `Z` standard deviations up (Wilson–Hilferty).
+    pub fn critical(&self) -> f64 {
+        let k = self.df() as f64;
+        if k == 0.0 { return 0.0; }
+        let s = 2.0 / (9.0 * k);
+        k * (1.0 - s + Z * s.sqrt()).powi(3)
+    }
+
+    /// Everything accepted, and the two histograms agree.
+    pub fn passes(&self) -> bool { self.rejected == [0, 0] && self.chi2 <= self.critical() }
+}
+
+/// `samples` honest and `samples` simulated transcripts on fresh uniform challenges, each checked
+/// by the driver, then compared by a two-sample chi-square test. Expect about five samples per
+/// possible transcript; with fewer the test has little power (and over a real group every
+/// transcript is distinct, so only `rejected` is informative).
+pub fn sample<P: SigmaProtocol, R: RngCore + ?Sized>(driver: Driver, x: &P::Statement, w: &P::Witness, samples: usize, rng: &mut R) -> Report {
+    let mut counts: HashMap<Bytes, [u64; 2]> = HashMap::new();
+    let mut rejected = [0; 2];
+    for _ in 0..samples {
+        for (side, rejected) in rejected.iter_mut().enumerate() {
+            let e = driver.draw::<P::Challenge, _>(rng);
+            let t = if side == 0 { honest::<P, _>(&driver, x, w, &e, rng) } else { simulated::<P, _>(&driver, x, &e, rng) };
+            let t = t.expect("drawn challenges decode");
+            if !driver.accepts::<P>(x, &t) { *rejected += 1; }
+            // Count by the challenge P sees: distinct FS oracle bytes reduce to the same scalar.
+            let c = encode(&driver.challenge::<P::Challenge>(&t.1).expect("drawn challenges decode"));
+            counts.entry((t.0, c, t.2)).or_default()[side] += 1;
+        }
+    }
+    let chi2 = counts.values().map(|&[h, s]| (h as f64 - s as f64).powi(2) / (h + s) as f64).sum();
+    Report { samples, cells: counts.len(), rejected, chi2 }
+}
+
+/// Why `exact` found the distributions apart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Difference {
+    /// e is not a valid challenge for the driver.
+    BadChallenge,
+    /// `commit` or `simulate` draws its coins other than as a fixed number of `Zq::random`
+    /// calls, or needs more than `MAX_TAPES` coin sequences.
+    Unenumerable,
+    /// A transcript the driver's check rejects, from the honest or the simulated side.
+    Rejected { simulated: bool, transcript: Bytes },
+    /// A transcript with probability honest.0 / honest.1 under the prover and
+    /// simulated.0 / simulated.1 under the simulator.
+    Differs { transcript: Bytes, honest: (u64, u64), simulated: (u64, u64) },
+}
+
+/// Coins for `exact`: draw k is digit k in [0, q), laid out so `Zq::<Q>::random` returns it.
+struct Tape {
+    digits: Vec<u64>,
+    read: usize,
+    odd: bool,
+}
+
+impl RngCore for Tape {
+    fn next_u32(&mut self) -> u32 { self.odd = true; 0 }
+    fn next_u64(&mut self) -> u64 { self.odd = true; 0 }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if dest.len() != <Zq<0> as Challenge>::BYTES { self.odd = true; return; }
+        dest.fill(0);
+        dest[..8].copy_from_slice(&self.digits.get(self.read).copied().unwrap_or(0).to_le_bytes());
+        self.read += 1;
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> core::result::Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// The distribution of `run` over uniform coins: (transcript counts, number of coin sequences).
+fn enumerate<const Q: u64>(
+    simulated: bool,
+    mut run: impl FnMut(&mut Tape) -> (Bytes, bool),
+) -> core::result::Result<(HashMap<Bytes, u64>, u64), Difference> {
+    let mut probe = Tape { digits: Vec::new(), read: 0, odd: false };
+    run(&mut probe);
+    let draws = probe.read;
+    let tapes = (0..draws).try_fold(1u64, |n, _| n.checked_mul(Q).filter(|&n| n <= MAX_TAPES)).ok_or(Difference::Unenumerable)?;
+    if probe.odd { return Err(Difference::Unenumerable); }
+
+    let mut counts = HashMap::new();
+    let mut digits = vec![0u64; draws];
+    for _ in 0..tapes {
+        let mut tape = Tape { digits: digits.clone(), read: 0, odd: false };
+        let (t, ok) = run(&mut tape);
+        if tape.odd || tape.read != draws { return Err(Difference::Unenumerable); }
+        if !ok { return Err(Difference::Rejected { simulated, transcript: t }); }
+        *counts.entry(t).or_default() += 1;
+        // Next coin sequence (odometer, base q).
+        for d in digits.iter_mut() {
+            *d += 1;
+            if *d < Q { break; }
+            *d = 0;
+        }
+    }
+    Ok((counts, tapes))
+}
+
+/// Special HVZK over `Toy<Q>`, exactly: for challenge e, every coin sequence of `commit` and of
+/// `simulate` is run, every transcript must pass the driver's check, and each transcript must be
+/// equally likely on both sides. Returns the number of distinct transcripts.
+pub fn exact<P, const Q: u64>(driver: Driver, x: &P::Statement, w: &P::Witness, e: &[u8]) -> core::result::Result<usize, Difference>
+where
+    P: SigmaProtocol<Challenge = Zq<Q>>,
+{
+    driver.challenge::<Zq<Q>>(e).ok_or(Difference::BadChallenge)?;
+    let (h, h_total) = enumerate::<Q>(false, |tape| {
+        let t = honest::<P, _>(&driver, x, w, e, tape).expect("checked above");
+        let ok = driver.accepts::<P>(x, &t);
+        (t, ok)
+    })?;
+    let (s, s_total) = enumerate::<Q>(true, |tape| {
+        let t = simulated::<P, _>(&driver, x, e, tape).expect("checked above");
+        let ok = driver.accepts::<P>(x, &t);
+        (t, ok)
+    })?;
+
+    for t in h.keys().chain(s.keys()) {
+        let (h_n, s_n) = (h.get(t).copied().unwrap_or(0), s.get(t).copied().unwrap_or(0));
+        if h_n as u128 * s_total as u128 != s_n as u128 * h_total as u128 {
+            return Err(Difference::Differs { transcript: t.clone(), honest: (h_n, h_total), simulated: (s_n, s_total) });
+        }
+    }
+    Ok(h.len().max(s.len()))
+}
@@ -0,0 +1,6 @@
+//! Test harnesses for protocols built on this crate: properties every `SigmaProtocol` should
+//! have, checked by running the protocol's own code against instrumented oracles.
+
+pub mod extract;      // special soundness: rewind at the challenge, extract, check the relation
+pub mod hvzk;         // honest-verifier ZK: simulated vs honest transcripts, chi-square and exact
+pub mod toy;          // a prime-order group small enough to enumerate
@@ -0,0 +1,130 @@
+//! A toy prime-order group small enough to enumerate: the order-Q subgroup of Z_p^* for a safe
+//! prime p = 2Q + 1, written additively, with scalars `Zq<Q>`.
+//!
+//! Discrete logarithms are trivial here; the point is that every transcript of a protocol over
+//! `Toy<Q>` can be listed (Q^k of them for k scalars), so `testing::hvzk` can compare transcript
+//! distributions exactly or with a chi-square test. Q = 5, 11, 23 and 83 are safe-prime orders.
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::groups::{PrimeField, PrimeGroup};
+use crate::{CanonicalDecode, CanonicalEncode, Challenge, U64Challenge};
+
+/// Z_q, for a prime q = `Q` < 2^31. Encoded as 8 little-endian bytes (canonical: below q).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Zq<const Q: u64>(u64);
+
+impl<const Q: u64> Zq<Q> {
+    /// The canonical representative in [0, q).
+    pub fn value(&self) -> u64 { self.0 }
+
+    fn pow(self, mut k: u64) -> Self {
+        let (mut acc, mut base) = (1, self.0);
+        while k > 0 {
+            if k & 1 == 1 { acc = acc * base % Q; }
+            base = base * base % Q;
+            k >>= 1;
+        }
+        Zq(acc)
+    }
+}
+
+impl<const Q: u64> Add for Zq<Q> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self { Zq((self.0 + rhs.0) % Q) }
+}
+impl<const Q: u64> Sub for Zq<Q> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self { Zq((self.0 + Q - rhs.0) % Q) }
+}
+impl<const Q: u64> Mul for Zq<Q> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self { Zq(self.0 * rhs.0 % Q) }
+}
+impl<const Q: u64> Neg for Zq<Q> {
+    type Output = Self;
+    fn neg(self) -> Self { Zq((Q - self.0) % Q) }
+}
+
+impl<const Q: u64> CanonicalEncode for Zq<Q> {
+    fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.0.to_le_bytes()); }
+}
+impl<const Q: u64> CanonicalDecode for Zq<Q> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let U64Challenge(v) = U64Challenge::decode(input)?;
+        (v < Q).then_some(Zq(v))
+    }
+}
+
+/// 16 oracle bytes, little-endian, reduced mod q (bias below q / 2^128).
+impl<const Q: u64> Challenge for Zq<Q> {
+    const BYTES: usize = 16;
+    fn from_oracle_bytes(_label: &str, input: &[u8]) -> Self {
+        let mut wide = [0u8; 16];
+        let n = input.len().min(16);
+        wide[..n].copy_from_slice(&input[..n]);
+        Zq((u128::from_le_bytes(wide) % Q as u128) as u64)
+    }
+}
+
+impl<const Q: u64> PrimeField for Zq<Q> {
+    fn zero() -> Self { Zq(0) }
+    fn one() -> Self { Zq(1 % Q) }
+    fn from_u64(v: u64) -> Self { Zq(v % Q) }
+    fn invert(&self) -> Option<Self> { (self.0 != 0).then(|| self.pow(Q - 2)) }
+}
+
+/// The order-Q subgroup of Z_p^*, p = 2Q + 1 (which must be prime), generated by 4. Encoded as
+/// 8 little-endian bytes; decoding checks subgroup membership.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Toy<const Q: u64>(u64);
+
+impl<const Q: u64> Toy<Q> {
+    const P: u64 = 2 * Q + 1;
+
+    /// The residue mod p representing this element.
+    pub fn value(&self) -> u64 { self.0 }
+
+    fn pow(self, mut k: u64) -> Self {
+        let (mut acc, mut base) = (1, self.0);
+        while k > 0 {
+            if k & 1 == 1 { acc = acc * base % Self::P; }
+            base = base * base % Self::P;
+            k >>= 1;
+        }
+        Toy(acc)
+    }
+}
+
+impl<const Q: u64> Add for Toy<Q> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self { Toy(self.0 * rhs.0 % Self::P) }
+}
+impl<const Q: u64> Neg for Toy<Q> {
+    type Output = Self;
+    fn neg(self) -> Self { self.pow(Q - 1) }
+}
+impl<const Q: u64> Sub for Toy<Q> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self { self + -rhs }
+}
+impl<const Q: u64> Mul<Zq<Q>> for Toy<Q> {
+    type Output = Self;
+    fn mul(self, rhs: Zq<Q>) -> Self { self.pow(rhs.0) }
+}
+
+impl<const Q: u64> CanonicalEncode for Toy<Q> {
+    fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.0.to_le_bytes()); }
+}
+impl<const Q: u64> CanonicalDecode for Toy<Q> {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let U64Challenge(v) = U64Challenge::decode(input)?;
+        (v != 0 && v < Self::P && Self(v).pow(Q) == Self::identity()).then_some(Self(v))
+    }
+}
+
+impl<const Q: u64> PrimeGroup for Toy<Q> {
+    type Scalar = Zq<Q>;
+    fn generator() -> Self { Toy(4 % Self::P) }
+    fn identity() -> Self { Toy(1) }
+}
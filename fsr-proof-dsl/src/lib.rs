@@ -894,6 +894,7 @@ struct ProveArgs {
     respond_stream: Option<ExprClosure>,
     bind: Option<ExprClosure>,
     require: Vec<String>,
+    challenge_len: Option<Expr>,
 }
 
 impl Parse for ProveArgs {
@@ -909,6 +910,7 @@ impl Parse for ProveArgs {
         let mut respond_stream = None;
         let mut bind = None;
         let mut require: Vec<String> = Vec::new();
+        let mut challenge_len = None;
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
             input.parse::<Token![=]>()?;
@@ -927,6 +929,7 @@ impl Parse for ProveArgs {
                 "respond" => respond = Some(input.parse()?),
                 "respond_stream" => respond_stream = Some(input.parse()?),
                 "bind" => bind = Some(input.parse()?),
+                "challenge_len" => challenge_len = Some(input.parse()?),
                 "require" | "required" => {
                     let content;
                     syn::bracketed!(content in input);
@@ -953,6 +956,7 @@ impl Parse for ProveArgs {
             respond_stream,
             bind,
             require,
+            challenge_len,
         })
     }
 }
@@ -1097,6 +1101,14 @@ fn expand_prove_fischlin(args: ProveArgs) -> TokenStream2 {
     })
 }
 
+/// FS challenge length in bytes: `challenge_len = <expr>` if given, else 32.
+fn fs_challenge_len(challenge_len: Option<Expr>) -> TokenStream2 {
+    match challenge_len {
+        Some(e) => quote!(#e),
+        None => quote!(32usize),
+    }
+}
+
 fn expand_prove_fs(args: ProveArgs) -> TokenStream2 {
     let ProveArgs {
         oracle,
@@ -1109,6 +1121,7 @@ fn expand_prove_fs(args: ProveArgs) -> TokenStream2 {
         respond_stream,
         bind,
         require,
+        challenge_len,
         ..
     } = args;
 
@@ -1116,6 +1129,7 @@ fn expand_prove_fs(args: ProveArgs) -> TokenStream2 {
         return Error::new(rs.span(), "`respond_stream` is only supported for transform = \"fischlin\"")
             .to_compile_error();
     }
+    let challenge_len = fs_challenge_len(challenge_len);
 
     if !require.is_empty() {
         match &bind {
@@ -1155,7 +1169,7 @@ fn expand_prove_fs(args: ProveArgs) -> TokenStream2 {
             #bind_hook_once
 
             // Derive one challenge based on transcript state
-            let __challenge_len = 32usize; // fixed-size challenge, independent of b
+            let __challenge_len: usize = #challenge_len; // protocol-chosen, independent of b
             let __challenge = __oracle.derive_challenge("e_i", &[], __challenge_len);
 
             // Respond once
@@ -1264,6 +1278,7 @@ struct VerifyArgs {
     sigma_verify: ExprClosure,
     bind: Option<ExprClosure>,
     require: Vec<String>,
+    challenge_len: Option<Expr>,
 }
 
 impl Parse for VerifyArgs {
@@ -1276,6 +1291,7 @@ impl Parse for VerifyArgs {
         let mut sigma_verify = None;
         let mut bind = None;
         let mut require: Vec<String> = Vec::new();
+        let mut challenge_len = None;
 
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
@@ -1292,6 +1308,7 @@ impl Parse for VerifyArgs {
                 "proof" => proof = Some(input.parse()?),
                 "sigma_verify" => sigma_verify = Some(input.parse()?),
                 "bind" => bind = Some(input.parse()?),
+                "challenge_len" => challenge_len = Some(input.parse()?),
                 "require" | "required" => {
                     let content;
                     syn::bracketed!(content in input);
@@ -1315,6 +1332,7 @@ impl Parse for VerifyArgs {
             sigma_verify: sigma_verify.ok_or_else(|| Error::new(Span::call_site(), "missing `sigma_verify`"))?,
             bind,
             require,
+            challenge_len,
         })
     }
 }
@@ -1327,6 +1345,7 @@ fn expand_verify_fs(args: VerifyArgs) -> TokenStream2 {
     let sigma_verify = args.sigma_verify;
     let bind = args.bind;
     let require = args.require;
+    let challenge_len = fs_challenge_len(args.challenge_len);
 
     if !require.is_empty() {
         match &bind {
@@ -1343,7 +1362,7 @@ fn expand_verify_fs(args: VerifyArgs) -> TokenStream2 {
     }
 
     let bind_hook_each = if let Some(bc) = bind {
-        quote! {{ let mut __b = #bc; __b(&mut __oracle, __i, &proof.m[__i]); }}
+        quote! {{ let mut __b = #bc; __b(&mut __oracle, __i, &__proof.m[__i]); }}
     } else { quote!{} };
 
     quote!({
@@ -1353,6 +1372,7 @@ fn expand_verify_fs(args: VerifyArgs) -> TokenStream2 {
         let __stmt: &[u8] = ::core::convert::AsRef::<[u8]>::as_ref(&__stmt_owned);
         let __sidb: &[u8] = ::core::convert::AsRef::<[u8]>::as_ref(&__sid_owned);
         let __proof = (#proof);
+        let __challenge_len: usize = #challenge_len;
         let mut __ok = true;
         if !__proof.is_well_formed() { __ok = false; } else {
             fsr_core::TranscriptRuntime::absorb(&mut __oracle, "mode", b"FS");
@@ -1363,7 +1383,7 @@ fn expand_verify_fs(args: VerifyArgs) -> TokenStream2 {
                 #bind_hook_each
             }
             for __i in 0..__proof.m.len() {
-                let __e_bytes = __oracle.derive_challenge("e_i", &[], 32);
+                let __e_bytes = __oracle.derive_challenge("e_i", &[], __challenge_len);
                 fsr_core::TranscriptRuntime::absorb(&mut __oracle, "e_i", &__e_bytes);
                 fsr_core::TranscriptRuntime::absorb(&mut __oracle, "z_i", &__proof.z[__i]);
                 if !(#sigma_verify)(__i, &__proof.m[__i], &__e_bytes, &__proof.z[__i]) { __ok = false; break; }